@@ -0,0 +1,85 @@
+use common::combinator::one_of;
+use common::{Answer, Error, ParseError, Solution};
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+fn no_digit(i: usize) -> Error {
+    Error::Unsolvable(format!("line {} has no digit", i + 1))
+}
+
+pub fn solve_part_1(input: &str) -> Result<i32, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let first = line.chars().find_map(|c| c.to_digit(10)).ok_or_else(|| no_digit(i))?;
+            let last = line.chars().rev().find_map(|c| c.to_digit(10)).ok_or_else(|| no_digit(i))?;
+
+            Ok((first * 10 + last) as i32)
+        })
+        .sum()
+}
+
+pub fn solve_part_2(input: &str) -> Result<i32, Error> {
+    let digit = one_of(vec![
+        ("1", 1), ("2", 2), ("3", 3), ("4", 4), ("5", 5), ("6", 6), ("7", 7), ("8", 8), ("9", 9),
+        ("one", 1), ("two", 2), ("three", 3), ("four", 4), ("five", 5), ("six", 6), ("seven", 7), ("eight", 8), ("nine", 9),
+    ]);
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut digits = line
+                .char_indices()
+                .filter_map(|(i, _)| digit(&line[i..]).ok())
+                .map(|(value, _)| value);
+
+            let first = digits.next().ok_or_else(|| no_digit(i))?;
+            let last = digits.next_back().unwrap_or(first);
+
+            Ok(first * 10 + last)
+        })
+        .sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_1(input)?.into())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_2(input)?.into())
+    }
+}
+
+#[test]
+fn example_part_1() {
+    let result = solve_part_1(include_str!("../res/example_part_1"));
+    assert_eq!(result, Ok(142));
+}
+
+#[test]
+fn example_part_2() {
+    let result = solve_part_2(include_str!("../res/example_part_2"));
+    assert_eq!(result, Ok(281));
+}
+
+#[test]
+fn line_without_digit() {
+    let error = Err(Error::Unsolvable("line 2 has no digit".to_string()));
+    assert_eq!(solve_part_1("a1b\nabc\n"), error);
+    assert_eq!(solve_part_2("one\nabc\n"), error);
+    assert_eq!(solve_part_1(include_str!("../res/example_part_2")), error);
+}
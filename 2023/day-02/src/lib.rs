@@ -0,0 +1,157 @@
+use std::vec::Vec;
+use common::combinator::{labelled, lines, map, number, one_of, pair, parse_all, separated, spaces, spanned, tag, terminated, Parser};
+use common::{Answer, Error, ParseError, Solution};
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+#[derive(Clone)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+enum Entry {
+    Red(i32),
+    Green(i32),
+    Blue(i32),
+}
+
+impl Entry {
+    /// `<count> <color>`, e.g. `3 blue`.
+    fn parser<'a>() -> impl Parser<'a, Self> {
+        let color = one_of(vec![("red", Color::Red), ("green", Color::Green), ("blue", Color::Blue)]);
+
+        map(pair(terminated(number(), spaces()), color), |(value, color)| match color {
+            Color::Red => Entry::Red(value),
+            Color::Green => Entry::Green(value),
+            Color::Blue => Entry::Blue(value),
+        })
+    }
+}
+
+struct BallCount {
+    n_red: i32,
+    n_green: i32,
+    n_blue: i32,
+}
+
+impl BallCount {
+    fn power(self: &BallCount) -> i32 { self.n_red * self.n_green * self.n_blue }
+}
+
+impl From<Vec<Entry>> for BallCount {
+    fn from(entries: Vec<Entry>) -> Self {
+        let mut red_entries: Vec<i32> = Vec::new();
+        let mut green_entries: Vec<i32> = Vec::new();
+        let mut blue_entries: Vec<i32> = Vec::new();
+
+        for e in entries {
+            match e {
+                Entry::Red(value) => red_entries.push(value),
+                Entry::Green(v) => green_entries.push(v),
+                Entry::Blue(v) => blue_entries.push(v)
+            }
+        }
+
+        BallCount {
+            n_red: red_entries.iter().sum(),
+            n_green: green_entries.iter().sum(),
+            n_blue: blue_entries.iter().sum(),
+        }
+    }
+}
+
+pub struct Game {
+    id: i32,
+    requirements: BallCount,
+}
+
+impl Game {
+    fn is_possible_for(self: &Game, given_balls: BallCount) -> bool {
+        let enought_red = given_balls.n_red >= self.requirements.n_red;
+        let enought_green = given_balls.n_green >= self.requirements.n_green;
+        let enought_blue = given_balls.n_blue >= self.requirements.n_blue;
+
+        enought_red && enought_green && enought_blue
+    }
+}
+
+pub fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    let round = map(separated(Entry::parser(), pair(tag(","), spaces())), BallCount::from);
+    let rounds = separated(round, pair(tag(";"), spaces()));
+    let records = parse_all(input, lines(labelled("Game", spanned(number::<i32>()), rounds)))?;
+
+    let mut games: Vec<Game> = Vec::new();
+
+    for (game_index, ((game_id, game_id_str), rounds)) in (0_i32..).zip(records) {
+        if game_index + 1 != game_id {
+            return Err(ParseError::at(input, game_id_str, format!("game {}", game_index + 1)));
+        }
+
+        let game_requirements = rounds
+            .into_iter()
+            .reduce(|lhs, rhs|
+                BallCount {
+                    n_red: lhs.n_red.max(rhs.n_red),
+                    n_green: lhs.n_green.max(rhs.n_green),
+                    n_blue: lhs.n_blue.max(rhs.n_blue),
+                }
+            ).unwrap();
+
+        games.push(Game{ id: game_id, requirements: game_requirements });
+    }
+
+    Ok(games)
+}
+
+pub fn solve_part_1(games: &[Game]) -> i32 {
+    games
+        .iter()
+        .filter(|g| g.is_possible_for(BallCount { n_red: 12, n_green: 13, n_blue: 14 }))
+        .map(|g| g.id)
+        .sum()
+}
+
+pub fn solve_part_2(games: &[Game]) -> i32 {
+    games.iter().map(|g| g.requirements.power()).sum()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_games(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_1(input).into())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_2(input).into())
+    }
+}
+
+#[test]
+fn example_part_1() {
+    let result = solve_part_1(&parse_games(include_str!("../res/example")).unwrap());
+    assert_eq!(result, 8);
+}
+
+#[test]
+fn example_part_2() {
+    let result = solve_part_2(&parse_games(include_str!("../res/example")).unwrap());
+    assert_eq!(result, 2286);
+}
+
+#[test]
+fn parse_error_position() {
+    let error = parse_games("Game 1: 3 blue, 4 red\nGame 2: 1 red; 2 purple\n").err().unwrap();
+    assert_eq!(error.to_string(), "2:18: expected 'red', 'green' or 'blue', found 'purple'");
+}
@@ -0,0 +1,200 @@
+#![allow(clippy::len_zero, clippy::needless_return)]
+
+use std::collections::HashSet;
+use common::{Answer, Error, ParseError, Solution};
+use grid::Grid;
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+pub type SchematicData = Grid<char>;
+
+pub struct SchematicMetaData {
+    symbols: HashSet<char>,
+}
+
+struct SchematicNumberPosition {
+    i: usize,
+    j_begin: usize,
+    j_end: usize,
+}
+
+struct SchematicSymbolPosition {
+    i: usize,
+    j: usize,
+}
+
+struct SchematicNumberBoundingBox {
+    i_min: usize,
+    i_max: usize,
+    j_min: usize,
+    j_max: usize,
+}
+
+pub fn parse_engine_schematic(input: &str) -> Result<(SchematicData, SchematicMetaData), ParseError> {
+    let schematic: SchematicData = Grid::parse_chars(input)?;
+
+    let symbols = {
+        let mut tmp = schematic.iter().map(|(_, c)| c).filter(|c| !c.is_numeric() && **c != '.').cloned().collect::<Vec<char>>();
+        tmp.sort();
+        tmp.dedup();
+
+        HashSet::from_iter(tmp.iter().cloned())
+    };
+
+    Ok((schematic, SchematicMetaData{ symbols }))
+}
+
+fn scan_for_candidates(schematic: &SchematicData) -> Vec<SchematicNumberPosition> {
+    let mut found_numbers: Vec<SchematicNumberPosition> = Vec::new();
+    let mut scan_buffer: Vec<usize> = Vec::new();
+
+    for i in 0..schematic.n_rows() {
+        for j in 0..schematic.n_columns() {
+            let cell = schematic.row(i)[j];
+
+            if cell.is_numeric() {
+                scan_buffer.push(j);
+            }
+            else if scan_buffer.len() > 0 {
+                found_numbers.push(
+                    SchematicNumberPosition{
+                        i,
+                        j_begin: *scan_buffer.first().unwrap(),
+                        j_end: *scan_buffer.last().unwrap(),
+                    }
+                );
+                scan_buffer.clear();
+            }
+        }
+
+        if !scan_buffer.is_empty() {
+            found_numbers.push(
+                SchematicNumberPosition{
+                    i,
+                    j_begin: *scan_buffer.first().unwrap(),
+                    j_end: *scan_buffer.last().unwrap(),
+                }
+            );
+            scan_buffer.clear();
+        }
+    }
+
+    found_numbers
+}
+
+fn snbb_from_number_position(schematic: &SchematicData, pos: &SchematicNumberPosition) -> SchematicNumberBoundingBox {
+    SchematicNumberBoundingBox{
+        i_min: if pos.i == 0 { pos.i } else { pos.i - 1 },
+        i_max: if pos.i == schematic.n_rows() - 1 { pos.i } else { pos.i + 1 },
+        j_min: if pos.j_begin == 0 { pos.j_begin } else { pos.j_begin - 1 },
+        j_max: if pos.j_end == schematic.n_columns() - 1 { pos.j_end } else { pos.j_end + 1},
+    }
+}
+
+fn is_part_number(schematic: &SchematicData, meta: &SchematicMetaData, candidate_pos: &SchematicNumberPosition) -> bool {
+    let snbb = snbb_from_number_position(schematic, candidate_pos);
+
+    for i in snbb.i_min..=snbb.i_max {
+        for j in snbb.j_min..=snbb.j_max {
+            if meta.symbols.contains(&schematic.row(i)[j]) {
+                return true;
+            }
+        }
+    }
+
+    return false;
+}
+
+fn value_of_schematic_number(schematic: &SchematicData, pos: &SchematicNumberPosition) -> i32 {
+    let mut number_string: String = String::new();
+
+    for j in pos.j_begin..=pos.j_end {
+        number_string.push(schematic.row(pos.i)[j]);
+    }
+
+    number_string.parse::<i32>().unwrap()
+}
+
+pub fn solve_part_1(schematic: &SchematicData, meta: &SchematicMetaData) -> i32 {
+    scan_for_candidates(schematic)
+        .iter()
+        .filter(|c| is_part_number(schematic, meta, c))
+        .map(|pn| value_of_schematic_number(schematic, pn))
+        .sum()
+}
+
+pub fn solve_part_2(schematic: &SchematicData, meta: &SchematicMetaData) -> i32 {
+    let part_number_candidates = scan_for_candidates(schematic);
+    let part_numbers = part_number_candidates.iter().filter(|c| is_part_number(schematic, meta, c)).collect::<Vec<&SchematicNumberPosition>>();
+
+    let gears_candidates = {
+        let mut tmp: Vec<SchematicSymbolPosition> = Vec::new();
+
+        for (i, row) in schematic.rows().enumerate() {
+            for (j, cell) in row.iter().enumerate() {
+                if *cell == '*' {
+                    tmp.push(SchematicSymbolPosition { i , j });
+                }
+            }
+        }
+
+        tmp
+    };
+
+    gears_candidates
+        .iter()
+        .map(|g| {
+            let adjacent_part_numbers = part_numbers
+                .iter()
+                .filter(|pn| {
+                    let bb = snbb_from_number_position(schematic, pn);
+                    bb.i_min <= g.i && g.i <= bb.i_max &&
+                    bb.j_min <= g.j && g.j <= bb.j_max
+                })
+                .cloned()
+                .collect::<Vec<&SchematicNumberPosition>>();
+
+            if adjacent_part_numbers.len() == 2 {
+                adjacent_part_numbers.iter().map(|pn| value_of_schematic_number(schematic, pn)).product()
+            }
+            else {
+                0
+            }
+        })
+        .sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input = (SchematicData, SchematicMetaData);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_engine_schematic(input)
+    }
+
+    fn part_1((schematic, meta): &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_1(schematic, meta).into())
+    }
+
+    fn part_2((schematic, meta): &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_2(schematic, meta).into())
+    }
+}
+
+#[test]
+fn example_part_1() {
+    let (schematic, meta) = parse_engine_schematic(include_str!("../res/example")).unwrap();
+    let result = solve_part_1(&schematic, &meta);
+    assert_eq!(result, 4361);
+}
+
+#[test]
+fn example_part_2() {
+    let (schematic, meta) = parse_engine_schematic(include_str!("../res/example")).unwrap();
+    let result = solve_part_2(&schematic, &meta);
+    assert_eq!(result, 467835);
+}
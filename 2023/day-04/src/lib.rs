@@ -0,0 +1,102 @@
+use std::collections::{HashSet, HashMap};
+use common::combinator::{labelled, lines, map, number, numbers, pair, parse_all, separated_pair, spaces, tag, Parser};
+use common::{Answer, Error, ParseError, Solution};
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+pub struct Scratchcard {
+    winning_numbers: HashSet<i32>,
+    numbers: Vec<i32>,
+}
+
+impl Scratchcard {
+    /// `Card <id>: <numbers> | <numbers>`.
+    fn parser<'a>() -> impl Parser<'a, Self> {
+        let numbers_and_winning_numbers = separated_pair(numbers(), pair(spaces(), tag("|")), numbers());
+
+        map(labelled("Card", number::<usize>(), numbers_and_winning_numbers), |(_, (winning_numbers, numbers))| {
+            Scratchcard { winning_numbers: HashSet::from_iter(winning_numbers), numbers }
+        })
+    }
+}
+
+impl Scratchcard {
+    fn n_matches(self: &Self) -> usize {
+         self.numbers
+            .iter()
+            .filter(|n| self.winning_numbers.contains(n))
+            .count()
+    }
+}
+
+pub fn parse_scratchcards(input: &str) -> Result<Vec<Scratchcard>, ParseError> {
+    parse_all(input, lines(Scratchcard::parser()))
+}
+
+pub fn solve_part_1(scratchcards: &[Scratchcard]) -> usize {
+    scratchcards
+        .iter()
+        .map(|card| match card.n_matches() {
+            0 => 0,
+            n => 1 << (n - 1),
+        })
+        .sum()
+}
+
+pub fn solve_part_2(scratchcards: &[Scratchcard]) -> usize {
+    let mut cache: HashMap<usize, usize> = HashMap::new();
+
+    for i in (0..scratchcards.len()).rev() {
+        assert!(!cache.contains_key(&i));
+
+        let inherited_amout: usize = (0..scratchcards[i].n_matches())
+            .map(|offset| {
+                cache.get(&(i+offset+1)).unwrap_or(&0)
+            })
+            .sum();
+
+        cache.insert(i, 1 + inherited_amout);
+    }
+
+    cache.iter().map(|item| item.1).sum()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+    const COLLAPSE_WHITESPACE: bool = true;
+
+    type Input = Vec<Scratchcard>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_scratchcards(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_1(input).into())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_2(input).into())
+    }
+}
+
+#[test]
+fn example_part_1() {
+    let result = solve_part_1(&parse_scratchcards(include_str!("../res/example")).unwrap());
+    assert_eq!(result, 13);
+}
+
+#[test]
+fn example_part_2() {
+    let result = solve_part_2(&parse_scratchcards(include_str!("../res/example")).unwrap());
+    assert_eq!(result, 30);
+}
+
+#[test]
+fn parse_error_position() {
+    let error = parse_scratchcards("Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61 30\n").err().unwrap();
+    assert_eq!(error.to_string(), "2:12: expected a number, found 'x2'");
+}
@@ -0,0 +1,371 @@
+use std::fmt;
+use std::str::FromStr;
+use common::combinator::{delimited, end_of_line, lines, numbers, pair, parse_all, preceded, rest_of_line, sections, spanned, tag, terminated, verify};
+use common::{Answer, Error, ParseError, Solution};
+use intervals::{Interval, IntervalSet, OffsetMap};
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+/// Piecewise map from one category of the almanac to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    offsets: OffsetMap,
+}
+
+impl Mapping {
+    /// `None` if the result does not fit in an `i64`.
+    pub fn apply_value(self: &Self, value: i64) -> Option<i64> {
+        self.offsets.apply(value)
+    }
+
+    pub fn identity() -> Self {
+        Mapping { offsets: OffsetMap::identity() }
+    }
+
+    /// All values that the mapping sends into `values`.
+    pub fn preimage(self: &Self, values: &IntervalSet) -> IntervalSet {
+        self.offsets.preimage(values)
+    }
+
+    /// The map that applies this mapping first and `next` to its result.
+    pub fn then(self: &Self, next: &Mapping) -> Mapping {
+        Mapping { offsets: self.offsets.then(&next.offsets) }
+    }
+
+    /// Lowest value that any of the `ranges` maps to, `None` if they are
+    /// empty. Within a piece the mapping only shifts, so only range and piece
+    /// starts are candidates.
+    fn lowest_value_of(self: &Self, ranges: &IntervalSet) -> Result<Option<i64>, Error> {
+        let values = ranges
+            .intervals()
+            .iter()
+            .flat_map(|range| {
+                let starts = self.offsets.breakpoints().filter(|start| range.contains(*start));
+                [range.start].into_iter().chain(starts)
+            })
+            .map(|value| self.apply_value(value).ok_or_else(|| Error::Unsolvable(format!("{value} maps beyond 64 bits"))))
+            .collect::<Result<Vec<i64>, Error>>()?;
+
+        Ok(values.into_iter().min())
+    }
+}
+
+impl fmt::Display for Mapping {
+    /// One line per piece that moves its values, e.g. `[98, 100) -> [50, 52)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.offsets)
+    }
+}
+
+/// The categories of the almanac, in the order the maps lead through them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Category {
+    Seed,
+    Soil,
+    Fertilizer,
+    Water,
+    Light,
+    Temperature,
+    Humidity,
+    Location,
+}
+
+impl Category {
+    pub const ALL: [Category; 8] = [
+        Category::Seed,
+        Category::Soil,
+        Category::Fertilizer,
+        Category::Water,
+        Category::Light,
+        Category::Temperature,
+        Category::Humidity,
+        Category::Location,
+    ];
+
+    pub fn name(self: &Self) -> &'static str {
+        match self {
+            Category::Seed => "seed",
+            Category::Soil => "soil",
+            Category::Fertilizer => "fertilizer",
+            Category::Water => "water",
+            Category::Light => "light",
+            Category::Temperature => "temperature",
+            Category::Humidity => "humidity",
+            Category::Location => "location",
+        }
+    }
+}
+
+impl FromStr for Category {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, String> {
+        Category::ALL
+            .into_iter()
+            .find(|category| category.name() == name)
+            .ok_or(format!("unknown category '{name}'"))
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    seed_to_soil_map: Mapping,
+    soil_to_fertilizer_map: Mapping,
+    fertilizer_to_water_map: Mapping,
+    water_to_light_map: Mapping,
+    light_to_temperature_map: Mapping,
+    temperature_to_humidity_map: Mapping,
+    humidity_to_location_map: Mapping,
+}
+
+/// `<destination> <source> <length>` of a line of a map.
+type MappingEntryNumbers<'a> = (Vec<i64>, &'a str);
+
+impl TryFrom<&str> for Almanac {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, ParseError> {
+        let seeds = delimited(tag("seeds:"), numbers::<i64>(), pair(end_of_line(), tag("\n")));
+
+        let map_header = terminated(
+            verify(rest_of_line(), |header| header.ends_with("map:"), "'<source>-to-<destination> map:'"),
+            end_of_line(),
+        );
+        let map_entry = spanned(verify(
+            verify(numbers::<i64>(), |numbers| numbers.len() == 3, "'<destination> <source> <length>'"),
+            |numbers| numbers[2] >= 0,
+            "a range with a length that is not negative",
+        ));
+        let map = spanned(preceded(map_header, lines(map_entry)));
+
+        let (seeds, maps) = parse_all(input, pair(seeds, sections(map)))?;
+
+        if maps.len() < 7 {
+            return Err(ParseError::at_end(input, "seeds and seven maps, separated by blank lines"));
+        }
+        if let Some((_, section)) = maps.get(7) {
+            return Err(ParseError::at(input, section, "end of input"));
+        }
+
+        fn parse_mapping(input: &str, entries: &[MappingEntryNumbers]) -> Result<Mapping, ParseError> {
+            let pieces = entries
+                .iter()
+                .map(|(numbers, span)| {
+                    let (destination_range_start, source_range_start, range_length) = (numbers[0], numbers[1], numbers[2]);
+                    let source_range = Interval::with_length(source_range_start, range_length);
+                    let offset = destination_range_start
+                        .checked_sub(source_range_start)
+                        .filter(|_| Interval::with_length(destination_range_start, range_length).is_some());
+
+                    match (source_range, offset) {
+                        (Some(source_range), Some(offset)) => Ok((source_range, offset)),
+                        _ => Err(ParseError::at(input, span, "ranges that end within 64 bits")),
+                    }
+                })
+                .collect::<Result<Vec<(Interval, i64)>, ParseError>>()?;
+
+            match OffsetMap::from_pieces(pieces) {
+                Ok(offsets) => Ok(Mapping { offsets }),
+                Err(i) => Err(ParseError::at(input, entries[i].1, "a source range not overlapping the others")),
+            }
+        }
+
+        Ok(Almanac {
+            seeds,
+            seed_to_soil_map: parse_mapping(input, &maps[0].0)?,
+            soil_to_fertilizer_map: parse_mapping(input, &maps[1].0)?,
+            fertilizer_to_water_map: parse_mapping(input, &maps[2].0)?,
+            water_to_light_map: parse_mapping(input, &maps[3].0)?,
+            light_to_temperature_map: parse_mapping(input, &maps[4].0)?,
+            temperature_to_humidity_map: parse_mapping(input, &maps[5].0)?,
+            humidity_to_location_map: parse_mapping(input, &maps[6].0)?,
+        })
+    }
+}
+
+impl Almanac {
+    /// The maps in order, the one at index `i` leading from `Category::ALL[i]` to the next category.
+    fn maps(self: &Self) -> [&Mapping; 7] {
+        [
+            &self.seed_to_soil_map,
+            &self.soil_to_fertilizer_map,
+            &self.fertilizer_to_water_map,
+            &self.water_to_light_map,
+            &self.light_to_temperature_map,
+            &self.temperature_to_humidity_map,
+            &self.humidity_to_location_map,
+        ]
+    }
+
+    /// The maps from `from` up to `to` collapsed into one; `from` must not come after `to`.
+    pub fn mapping(self: &Self, from: Category, to: Category) -> Mapping {
+        assert!(from <= to, "no map leads from {} back to {}", from.name(), to.name());
+
+        self.maps()[from as usize..to as usize]
+            .iter()
+            .fold(Mapping::identity(), |mapping, next| mapping.then(next))
+    }
+
+    /// The seven maps collapsed into one.
+    pub fn seed_to_location_map(self: &Self) -> Mapping {
+        self.mapping(Category::Seed, Category::Location)
+    }
+
+    /// The values of category `to` that belong to `value` of category `from`:
+    /// a single one following the maps, all of its preimages against them.
+    /// `None` for `i64::MAX`, which ends no interval.
+    pub fn lookup(self: &Self, from: Category, to: Category, value: i64) -> Option<IntervalSet> {
+        let value = Interval::with_length(value, 1)?;
+
+        match from <= to {
+            true => Some(self.mapping(from, to).offsets.apply_interval(value)),
+            false => Some(self.mapping(to, from).preimage(&value.into())),
+        }
+    }
+
+    fn lowest_location_of(self: &Self, seed_ranges: &IntervalSet) -> Result<i64, Error> {
+        self.seed_to_location_map()
+            .lowest_value_of(seed_ranges)?
+            .ok_or_else(|| Error::Unsolvable("there are no seeds".to_string()))
+    }
+}
+
+pub fn solve_part_1(almanac: &Almanac) -> Result<i64, Error> {
+    let seed_ranges: IntervalSet = almanac.seeds
+        .iter()
+        .map(|seed| Interval::with_length(*seed, 1).ok_or_else(|| Error::Unsolvable(format!("seed {seed} is out of range"))))
+        .collect::<Result<_, _>>()?;
+
+    almanac.lowest_location_of(&seed_ranges)
+}
+
+pub fn solve_part_2(almanac: &Almanac) -> Result<i64, Error> {
+    if !almanac.seeds.len().is_multiple_of(2) {
+        return Err(Error::Unsolvable(format!("{} seed numbers do not pair up into ranges", almanac.seeds.len())));
+    }
+
+    let seed_ranges: IntervalSet = almanac.seeds
+        .chunks(2)
+        .map(|pair| {
+            Interval::with_length(pair[0], pair[1])
+                .ok_or_else(|| Error::Unsolvable(format!("seed range {} {} is not a range", pair[0], pair[1])))
+        })
+        .collect::<Result<_, _>>()?;
+
+    almanac.lowest_location_of(&seed_ranges)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Almanac::try_from(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_1(input)?.into())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_2(input)?.into())
+    }
+}
+
+#[test]
+fn example_part_1() {
+    let result = solve_part_1(&Almanac::try_from(include_str!("../res/example")).unwrap());
+    assert_eq!(result, Ok(35));
+}
+
+#[test]
+fn example_part_2() {
+    let result = solve_part_2(&Almanac::try_from(include_str!("../res/example")).unwrap());
+    assert_eq!(result, Ok(46));
+}
+
+#[test]
+fn unpaired_seeds() {
+    let almanac = Almanac::try_from(include_str!("../res/example").replacen("seeds: 79 14 55 13", "seeds: 79 14 55", 1).as_str()).unwrap();
+    assert_eq!(solve_part_1(&almanac), Ok(43));
+    assert_eq!(solve_part_2(&almanac), Err(Error::Unsolvable("3 seed numbers do not pair up into ranges".to_string())));
+
+    let almanac = Almanac::try_from(include_str!("../res/example").replacen("seeds: 79 14 55 13", "seeds: 79 -14", 1).as_str()).unwrap();
+    assert_eq!(solve_part_2(&almanac), Err(Error::Unsolvable("seed range 79 -14 is not a range".to_string())));
+}
+
+#[test]
+fn parse_error_position() {
+    let input = include_str!("../res/example").replacen("50 98 2", "50 98", 1);
+    let error = Almanac::try_from(input.as_str()).err().unwrap();
+    assert_eq!(error.to_string(), "4:1: expected '<destination> <source> <length>', found '50 98'");
+}
+
+#[test]
+fn negative_range_length() {
+    let input = include_str!("../res/example").replacen("50 98 2", "50 98 -2", 1);
+    let error = Almanac::try_from(input.as_str()).err().unwrap();
+    assert_eq!(error.to_string(), "4:1: expected a range with a length that is not negative, found '50 98 -2'");
+}
+
+#[test]
+fn overlapping_source_ranges() {
+    let input = include_str!("../res/example").replacen("52 50 48", "52 51 48", 1);
+    let error = Almanac::try_from(input.as_str()).err().unwrap();
+    assert_eq!(error.to_string(), "4:1: expected a source range not overlapping the others, found '50 98 2'");
+}
+
+#[test]
+fn composed_map_matches_chain() {
+    let almanac = Almanac::try_from(include_str!("../res/example")).unwrap();
+    let composed = almanac.seed_to_location_map();
+
+    let chain = almanac.maps();
+
+    for seed in -10..120 {
+        let location = chain.iter().try_fold(seed, |value, mapping| mapping.apply_value(value));
+        assert_eq!(composed.apply_value(seed), location, "seed {seed}");
+    }
+
+    assert_eq!([79, 14, 55, 13].map(|seed| composed.apply_value(seed)), [82, 43, 86, 35].map(Some));
+    assert!(composed.to_string().starts_with("[0, 14) -> [22, 36)\n[14, 15) -> [43, 44)\n"));
+}
+
+#[test]
+fn reverse_lookup() {
+    let almanac = Almanac::try_from(include_str!("../res/example")).unwrap();
+    let seed_to_location = almanac.seed_to_location_map();
+
+    for location in -10..120 {
+        let seeds = almanac.lookup(Category::Location, Category::Seed, location).unwrap();
+        for seed in -40..150 {
+            assert_eq!(seeds.contains(seed), seed_to_location.apply_value(seed) == Some(location), "seed {seed} for location {location}");
+        }
+    }
+
+    assert_eq!(almanac.lookup(Category::Location, Category::Seed, 46).unwrap().to_string(), "{[82, 83)}");
+    assert_eq!(almanac.lookup(Category::Seed, Category::Soil, 79).unwrap().to_string(), "{[81, 82)}");
+    assert_eq!(almanac.lookup(Category::Fertilizer, Category::Soil, 53).unwrap().to_string(), "{[14, 15)}");
+    assert_eq!("humidity".parse::<Category>(), Ok(Category::Humidity));
+}
+
+#[test]
+fn values_at_the_bounds() {
+    let almanac = Almanac::try_from(include_str!("../res/example")).unwrap();
+
+    assert_eq!(almanac.lookup(Category::Seed, Category::Soil, i64::MAX), None);
+    assert_eq!(almanac.lookup(Category::Seed, Category::Soil, i64::MAX - 1).unwrap().to_string(), format!("{{[{}, {})}}", i64::MAX - 1, i64::MAX));
+    assert_eq!(almanac.lookup(Category::Location, Category::Seed, i64::MIN).unwrap().to_string(), format!("{{[{}, {})}}", i64::MIN, i64::MIN + 1));
+    assert_eq!(almanac.seed_to_location_map().apply_value(i64::MAX), Some(i64::MAX));
+
+    let almanac = Almanac::try_from(include_str!("../res/example").replacen("seeds: 79 14 55 13", "seeds: 9223372036854775807", 1).as_str()).unwrap();
+    assert_eq!(solve_part_1(&almanac), Err(Error::Unsolvable("seed 9223372036854775807 is out of range".to_string())));
+
+    let input = include_str!("../res/example").replacen("50 98 2", "9223372036854775807 98 2", 1);
+    let error = Almanac::try_from(input.as_str()).err().unwrap();
+    assert_eq!(error.to_string(), "4:1: expected ranges that end within 64 bits, found '9223372036854775807 98 2'");
+}
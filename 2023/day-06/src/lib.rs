@@ -0,0 +1,99 @@
+#![allow(dead_code)]
+
+use common::combinator::{delimited, end_of_line, many1, number, pair, parse_all, preceded, spaces, spanned, tag};
+use common::{Answer, Error, ParseError, Solution};
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+#[derive(Copy, Clone, Debug)]
+struct Race {
+    time: i64,
+    distance: i64,
+}
+
+impl Race {
+    fn n_ways_to_win(self: &Self) -> usize {
+        (0..=self.time)
+            .map(|i| i * (self.time - i))
+            .filter(|d| d > &self.distance)
+            .count()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RaceTable (Vec<Race>);
+
+impl TryFrom<&str> for RaceTable {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, ParseError> {
+        let column = |prefix: &'static str| delimited(
+            tag(prefix),
+            spanned(many1(preceded(spaces(), spanned(number::<u64>())))),
+            end_of_line(),
+        );
+
+        let ((time_digits, time_span), (distance_digits, distance_span)) = parse_all(input, pair(column("Time:"), column("Distance:")))?;
+
+        let concat_column = |digits: Vec<(u64, &str)>, span: &str| -> Result<i64, ParseError> {
+            digits
+                .iter()
+                .map(|(_, digits)| *digits)
+                .collect::<String>()
+                .parse::<i64>()
+                .map_err(|_| ParseError::at(input, span, "a number of at most 18 digits"))
+        };
+
+        let time: i64 = concat_column(time_digits, time_span)?;
+        let distance: i64 = concat_column(distance_digits, distance_span)?;
+
+        Ok(RaceTable (
+            Vec::from(
+                [
+                    Race {
+                        time,
+                        distance,
+                    }
+                ]
+            )
+        ))
+    }
+}
+
+impl RaceTable {
+    pub fn eval(self: &Self) -> usize {
+        self.0
+            .iter()
+            .map(|race| race.n_ways_to_win())
+            .product()
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+    const COLLAPSE_WHITESPACE: bool = true;
+
+    type Input = RaceTable;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        RaceTable::try_from(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(input.eval().into())
+    }
+}
+
+#[test]
+fn example() {
+    static EXAMPLE_INPUT: &str = include_str!("../res/example");
+    static EXAMPLE_ANSWER: usize = 71503;
+
+    let race_table = RaceTable::try_from(EXAMPLE_INPUT).unwrap();
+
+    let result: usize = race_table.eval();
+    assert_eq!(result, EXAMPLE_ANSWER);
+}
@@ -0,0 +1,313 @@
+#![allow(dead_code)]
+
+use std::collections::{HashMap, HashSet};
+use common::combinator::{assignment, end_of_line, lines, many1, pair, parenthesized_pair, parse_all, spanned, symbol, tag, terminated, word};
+use common::number_theory::crt;
+use common::{Answer, Error, ParseError, Solution};
+use graph::{Graph, NodeId};
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+#[derive(Debug)]
+enum Instruction {
+    Left,
+    Right
+}
+
+/// Every node has two edges, to its left and to its right successor.
+pub struct InstructionsAndNetwork {
+    instructions: Vec<Instruction>,
+    network: Graph<String>,
+}
+
+impl InstructionsAndNetwork {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let instruction = symbol(|c| match c {
+            'L' => Some(Instruction::Left),
+            'R' => Some(Instruction::Right),
+            _ => None,
+        }, "'L' or 'R'");
+        let instructions = terminated(many1(instruction), pair(end_of_line(), tag("\n")));
+
+        let entry = assignment(spanned(word()), parenthesized_pair(spanned(word())));
+
+        let (instructions, entries) = parse_all(input, pair(instructions, lines(entry)))?;
+
+        let mut network: Graph<String> = Graph::new();
+        for ((id, span), _) in &entries {
+            if network.contains(&id.to_string()) {
+                return Err(ParseError::at(input, span, "a node not defined before"));
+            }
+            network.add_node(id.to_string());
+        }
+
+        for ((id, _), ((left, left_span), (right, right_span))) in &entries {
+            for (successor, span) in [(left, left_span), (right, right_span)] {
+                if !network.contains(&successor.to_string()) {
+                    return Err(ParseError::at(input, span, "a node of the network"));
+                }
+                network.add_edge(id.to_string(), successor.to_string(), ());
+            }
+        }
+
+        Ok(InstructionsAndNetwork { instructions, network })
+    }
+
+    fn id(self: &Self, key: &str) -> Option<NodeId> {
+        self.network.id(&key.to_string())
+    }
+
+    fn ends_with(self: &Self, id: NodeId, suffix: char) -> bool {
+        self.network.key(id).ends_with(suffix)
+    }
+
+    /// The node reached from `id` by the instruction of `step`.
+    fn next(self: &Self, id: NodeId, step: usize) -> Result<NodeId, Error> {
+        let successor = match self.instructions[step % self.instructions.len()] {
+            Instruction::Left => self.network.edges(id).first(),
+            Instruction::Right => self.network.edges(id).get(1),
+        };
+
+        successor
+            .map(|(next, _)| *next)
+            .ok_or_else(|| Error::Unsolvable(format!("node {} does not have two successors", self.network.key(id))))
+    }
+
+    fn node(self: &Self, key: &str) -> Result<NodeId, Error> {
+        self.id(key).ok_or_else(|| Error::Unsolvable(format!("there is no node {key}")))
+    }
+
+    pub fn solve_part_1(self: &Self) -> Result<usize, Error> {
+        let (start, end) = (self.node("AAA")?, self.node("ZZZ")?);
+
+        let mut seen: HashSet<(NodeId, usize)> = HashSet::new();
+        let mut step_counter: usize = 0;
+        let mut curr_node_id = start;
+
+        while curr_node_id != end {
+            // Once a state repeats, the walk goes round in a cycle without ZZZ.
+            if !seen.insert((curr_node_id, step_counter % self.instructions.len())) {
+                return Err(Error::Unsolvable("ZZZ cannot be reached from AAA".to_string()));
+            }
+
+            curr_node_id = self.next(curr_node_id, step_counter)?;
+            step_counter += 1;
+        }
+
+        Ok(step_counter)
+    }
+
+    /// Follows the instructions from `start` until a (node, instruction index)
+    /// state repeats, from when on the walk is periodic.
+    fn walk(self: &Self, start: NodeId) -> Result<Walk, Error> {
+        let mut first_seen: HashMap<(NodeId, usize), usize> = HashMap::new();
+        let mut hits: Vec<usize> = Vec::new();
+
+        let mut id = start;
+        let mut step = 0;
+
+        loop {
+            let state = (id, step % self.instructions.len());
+            if let Some(prefix) = first_seen.insert(state, step) {
+                let (prefix_hits, cycle_hits) = hits.iter().partition(|hit| **hit < prefix);
+                return Ok(Walk { prefix, period: step - prefix, prefix_hits, cycle_hits });
+            }
+
+            if self.ends_with(id, 'Z') {
+                hits.push(step);
+            }
+
+            id = self.next(id, step)?;
+            step += 1;
+        }
+    }
+
+    pub fn solve_part_2(self: &Self) -> Result<usize, Error> {
+        let walks: Vec<Walk> = self.network
+            .nodes()
+            .filter(|id| self.ends_with(*id, 'A'))
+            .map(|id| self.walk(id))
+            .collect::<Result<_, _>>()?;
+
+        first_common_hit(&walks)
+            .ok_or_else(|| Error::Unsolvable("the ghosts never all stand on nodes ending in 'Z' at once".to_string()))
+    }
+}
+
+/// Steps at which a walk stands on a node ending in `Z`: the listed prefix
+/// hits, and from step `prefix` on every step congruent to a cycle hit
+/// modulo `period`.
+#[derive(Debug, PartialEq, Eq)]
+struct Walk {
+    prefix: usize,
+    period: usize,
+    prefix_hits: Vec<usize>,
+    /// Hits of the first round of the cycle, in `prefix..prefix + period`.
+    cycle_hits: Vec<usize>,
+}
+
+impl Walk {
+    fn is_hit(self: &Self, step: usize) -> bool {
+        match step < self.prefix {
+            true => self.prefix_hits.contains(&step),
+            false => self.cycle_hits.contains(&(self.prefix + (step - self.prefix) % self.period)),
+        }
+    }
+}
+
+/// First step at which all walks hit at once, `None` if there is none.
+///
+/// Before the longest prefix only its own hits are candidates. From there on
+/// the walks are all periodic, so each choice of one cycle hit per walk is a
+/// system of congruences for the Chinese remainder theorem.
+fn first_common_hit(walks: &[Walk]) -> Option<usize> {
+    let longest = walks.iter().max_by_key(|walk| walk.prefix)?;
+
+    if let Some(step) = longest.prefix_hits.iter().find(|step| walks.iter().all(|walk| walk.is_hit(**step))) {
+        return Some(*step);
+    }
+
+    let mut congruences: Vec<(i128, i128)> = vec![(0, 1)];
+    for walk in walks {
+        congruences = congruences
+            .iter()
+            .flat_map(|congruence| {
+                walk.cycle_hits
+                    .iter()
+                    .filter_map(|hit| crt([*congruence, (*hit as i128, walk.period as i128)]))
+            })
+            .collect();
+        congruences.sort();
+        congruences.dedup();
+    }
+
+    let start = longest.prefix as i128;
+
+    congruences
+        .iter()
+        .map(|(residue, modulus)| start + (residue - start).rem_euclid(*modulus))
+        .min()
+        .and_then(|step| usize::try_from(step).ok())
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Input = InstructionsAndNetwork;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        InstructionsAndNetwork::parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(InstructionsAndNetwork::solve_part_1(input)?.into())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(InstructionsAndNetwork::solve_part_2(input)?.into())
+    }
+}
+
+#[test]
+fn example_1() {
+    static EXAMPLE_INPUT: &str = include_str!("../res/example_1");
+    static EXAMPLE_ANSWER: usize = 2;
+
+    let data = InstructionsAndNetwork::parse(EXAMPLE_INPUT).unwrap();
+
+    let result = InstructionsAndNetwork::solve_part_1(&data);
+    assert_eq!(result, Ok(EXAMPLE_ANSWER));
+}
+
+#[test]
+fn example_2() {
+    static EXAMPLE_INPUT: &str = include_str!("../res/example_2");
+    static EXAMPLE_ANSWER: usize = 6;
+
+    let data = InstructionsAndNetwork::parse(EXAMPLE_INPUT).unwrap();
+
+    let result = InstructionsAndNetwork::solve_part_1(&data);
+    assert_eq!(result, Ok(EXAMPLE_ANSWER));
+}
+
+#[test]
+fn example_3() {
+    static EXAMPLE_INPUT: &str = include_str!("../res/example_3");
+    static EXAMPLE_ANSWER: usize = 6;
+
+    let data = InstructionsAndNetwork::parse(EXAMPLE_INPUT).unwrap();
+
+    let result = InstructionsAndNetwork::solve_part_2(&data);
+    assert_eq!(result, Ok(EXAMPLE_ANSWER));
+}
+
+#[cfg(test)]
+impl InstructionsAndNetwork {
+    /// Steps all ghosts in lockstep, for up to `limit` steps.
+    fn simulate_part_2(self: &Self, limit: usize) -> Option<usize> {
+        let mut ids: Vec<NodeId> = self.network.nodes().filter(|id| self.ends_with(*id, 'A')).collect();
+
+        for step in 0..limit {
+            if ids.iter().all(|id| self.ends_with(*id, 'Z')) {
+                return Some(step);
+            }
+            ids = ids.iter().map(|id| self.next(*id, step).unwrap()).collect();
+        }
+
+        None
+    }
+}
+
+#[test]
+fn adversarial_ghost_walks() {
+    let cases = [
+        // A pre-period, two Z nodes in the cycle with unequal gaps.
+        ("L\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11C, 11C)\n11C = (12Z, 12Z)\n12Z = (11D, 11D)\n11D = (11B, 11B)\n22A = (22Z, 22Z)\n22Z = (22A, 22A)\n", Some(7)),
+        // A Z node only before the cycle.
+        ("L\n\n33A = (33Z, 33Z)\n33Z = (33B, 33B)\n33B = (33B, 33B)\n22A = (22Z, 22Z)\n22Z = (22A, 22A)\n", Some(1)),
+        ("L\n\n33A = (33Z, 33Z)\n33Z = (33B, 33B)\n33B = (33B, 33B)\n22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22A, 22A)\n", None),
+        // Periods that share a factor, so only some residues are compatible.
+        ("L\n\n44A = (44B, 44B)\n44B = (44C, 44C)\n44C = (44D, 44D)\n44D = (44E, 44E)\n44E = (44Z, 44Z)\n44Z = (44A, 44A)\n55A = (55Z, 55Z)\n55Z = (55B, 55B)\n55B = (55C, 55C)\n55C = (55A, 55A)\n", Some(5)),
+        ("L\n\n44A = (44B, 44B)\n44B = (44Z, 44Z)\n44Z = (44C, 44C)\n44C = (44A, 44A)\n55A = (55B, 55B)\n55B = (55C, 55C)\n55C = (55Z, 55Z)\n55Z = (55D, 55D)\n55D = (55E, 55E)\n55E = (55A, 55A)\n", None),
+        // The state includes the instruction index: 66Z is passed on every other round only.
+        ("LR\n\n66A = (66B, 66Z)\n66B = (66A, 66A)\n66Z = (66A, 66A)\n77A = (77B, 77B)\n77B = (77C, 77C)\n77C = (77Z, 77Z)\n77Z = (77A, 77A)\n", None),
+        ("LLR\n\n66A = (66B, 66Z)\n66B = (66A, 66A)\n66Z = (66A, 66A)\n77A = (77B, 77B)\n77B = (77C, 77C)\n77C = (77D, 77D)\n77D = (77E, 77E)\n77E = (77F, 77F)\n77F = (77G, 77G)\n77G = (77Z, 77Z)\n77Z = (77A, 77A)\n", Some(15)),
+    ];
+
+    for (input, expected) in cases {
+        let data = InstructionsAndNetwork::parse(input).unwrap();
+
+        assert_eq!(data.solve_part_2().ok(), expected, "{input}");
+        assert_eq!(data.simulate_part_2(1000), expected, "{input}");
+    }
+}
+
+#[test]
+fn walk_of_a_ghost() {
+    let data = InstructionsAndNetwork::parse(include_str!("../res/example_3")).unwrap();
+
+    let walk = data.walk(data.id("22A").unwrap()).unwrap();
+    assert_eq!(walk, Walk { prefix: 1, period: 6, prefix_hits: vec![], cycle_hits: vec![3, 6] });
+    assert!([3, 6, 9, 12].iter().all(|step| walk.is_hit(*step)) && !walk.is_hit(0));
+}
+
+#[test]
+fn parse_error_position() {
+    let error = InstructionsAndNetwork::parse("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, CCC)\n").err().unwrap();
+    assert_eq!(error.to_string(), "4:13: expected a node of the network, found 'CCC'");
+
+    let error = InstructionsAndNetwork::parse("LR\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)\n").err().unwrap();
+    assert_eq!(error.to_string(), "4:1: expected a node not defined before, found 'AAA'");
+}
+
+#[test]
+fn part_1_without_an_answer() {
+    let data = InstructionsAndNetwork::parse(include_str!("../res/example_3")).unwrap();
+    assert_eq!(data.solve_part_1(), Err(Error::Unsolvable("there is no node AAA".to_string())));
+
+    let data = InstructionsAndNetwork::parse("LR\n\nAAA = (BBB, AAA)\nBBB = (AAA, BBB)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+    assert_eq!(data.solve_part_1(), Err(Error::Unsolvable("ZZZ cannot be reached from AAA".to_string())));
+}
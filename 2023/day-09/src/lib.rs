@@ -0,0 +1,222 @@
+use std::fmt;
+use common::combinator::{lines, map, numbers, parse_all};
+use common::number_theory::Rational;
+use common::{Answer, Error, ParseError, Solution};
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+/// Why a history cannot be extrapolated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtrapolationError {
+    /// No row of differences is all zeros, so no polynomial of a degree below
+    /// the number of values is determined by them.
+    NoZeroDifferences,
+    /// A value does not fit the arithmetic or the result does not fit into `i64`.
+    Overflow,
+}
+
+impl fmt::Display for ExtrapolationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtrapolationError::NoZeroDifferences => write!(f, "the differences never become all zeros"),
+            ExtrapolationError::Overflow => write!(f, "the values overflow"),
+        }
+    }
+}
+
+/// The polynomial through the values of a history at positions `0, 1, ...`,
+/// in Newton form: `p(x) = Σ coefficients[j] · x(x-1)⋯(x-j+1) / j!`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    coefficients: Vec<i128>,
+}
+
+impl Polynomial {
+    pub fn degree(self: &Self) -> usize {
+        self.coefficients.iter().rposition(|coefficient| *coefficient != 0).unwrap_or(0)
+    }
+
+    /// The value at `x`, computed exactly.
+    pub fn value_at(self: &Self, x: i64) -> Result<i64, ExtrapolationError> {
+        let overflow = ExtrapolationError::Overflow;
+
+        let mut value = Rational::ZERO;
+        let mut binomial = Rational::ONE;
+
+        for (j, coefficient) in self.coefficients.iter().enumerate() {
+            if j > 0 {
+                // binomial(x, j) from binomial(x, j - 1)
+                let factor = Rational::new(x as i128 - (j as i128 - 1), j as i128).ok_or(overflow)?;
+                binomial = binomial.checked_mul(&factor).ok_or(overflow)?;
+            }
+
+            let term = binomial.checked_mul(&Rational::from(*coefficient)).ok_or(overflow)?;
+            value = value.checked_add(&term).ok_or(overflow)?;
+        }
+
+        value.to_integer()
+            .and_then(|value| i64::try_from(value).ok())
+            .ok_or(overflow)
+    }
+}
+
+#[derive(Debug)]
+pub struct History (Vec<i64>);
+
+impl History {
+    /// The polynomial of lowest degree through the values, which is only
+    /// determined once a row of differences is all zeros. A single value is
+    /// taken as a constant.
+    pub fn interpolate(self: &Self) -> Result<Polynomial, ExtrapolationError> {
+        if let [value] = self.0[..] {
+            return Ok(Polynomial { coefficients: vec![value as i128] });
+        }
+
+        let mut coefficients: Vec<i128> = Vec::new();
+        let mut row: Vec<i128> = self.0.iter().map(|value| *value as i128).collect();
+
+        while row.iter().any(|value| *value != 0) {
+            if row.len() == 1 {
+                return Err(ExtrapolationError::NoZeroDifferences);
+            }
+
+            coefficients.push(row[0]);
+            row = row
+                .windows(2)
+                .map(|w| w[1].checked_sub(w[0]))
+                .collect::<Option<Vec<i128>>>()
+                .ok_or(ExtrapolationError::Overflow)?;
+        }
+
+        Ok(Polynomial { coefficients })
+    }
+
+    /// Degree of the polynomial that the values follow.
+    pub fn degree(self: &Self) -> Result<usize, ExtrapolationError> {
+        Ok(self.interpolate()?.degree())
+    }
+
+    /// The value `steps` positions after the last one, or before the first one for negative `steps`.
+    pub fn extrapolate(self: &Self, steps: i64) -> Result<i64, ExtrapolationError> {
+        let x = match steps >= 0 {
+            true => (self.0.len() as i64 - 1).checked_add(steps),
+            false => Some(steps),
+        };
+
+        self.interpolate()?.value_at(x.ok_or(ExtrapolationError::Overflow)?)
+    }
+
+    pub fn predict_next(self: &Self) -> Result<i64, ExtrapolationError> {
+        self.extrapolate(1)
+    }
+
+    pub fn extrapolate_backwards(self: &Self) -> Result<i64, ExtrapolationError> {
+        self.extrapolate(-1)
+    }
+}
+
+#[derive(Debug)]
+pub struct Oasis (pub Vec<History>);
+
+impl Oasis {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let histories = parse_all(input, lines(map(numbers::<i64>(), History)))?;
+
+        Ok(Oasis(histories))
+    }
+
+    /// Sum of the values `steps` away of all histories.
+    pub fn sum_of_extrapolations(self: &Self, steps: i64) -> Result<i64, Error> {
+        self.0
+            .iter()
+            .enumerate()
+            .try_fold(0i64, |sum, (i, history)| {
+                let value = history
+                    .extrapolate(steps)
+                    .map_err(|error| Error::Unsolvable(format!("history {}: {error}", i + 1)))?;
+                sum.checked_add(value).ok_or(Error::Unsolvable("the sum overflows".to_string()))
+            })
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    type Input = Oasis;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Oasis::parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(input.sum_of_extrapolations(1)?.into())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(input.sum_of_extrapolations(-1)?.into())
+    }
+}
+
+#[test]
+fn example_part_1() {
+    static INPUT: &str = include_str!("../res/example");
+    static ANSWER: i64 = 114;
+
+    let oasis = Oasis::parse(INPUT).unwrap();
+
+    let result: i64 = oasis.0
+        .iter()
+        .map(|h| h.predict_next().unwrap())
+        .sum();
+
+    assert_eq!(result, ANSWER);
+}
+
+#[test]
+fn example_part_2() {
+    static INPUT: &str = include_str!("../res/example");
+    static ANSWER: i64 = 2;
+
+    let oasis = Oasis::parse(INPUT).unwrap();
+
+    let result: i64 = oasis.0
+        .iter()
+        .map(|h| h.extrapolate_backwards().unwrap())
+        .sum();
+
+    assert_eq!(result, ANSWER);
+}
+
+#[test]
+fn extrapolate_steps_and_degree() {
+    let oasis = Oasis::parse(include_str!("../res/example")).unwrap();
+    let [first, second, third] = &oasis.0[..] else { panic!("three histories expected") };
+
+    assert_eq!([first.degree(), second.degree(), third.degree()], [Ok(1), Ok(2), Ok(3)]);
+    assert_eq!((first.extrapolate(3), first.extrapolate(-2)), (Ok(24), Ok(-6)));
+    assert_eq!((third.extrapolate(1), third.extrapolate(2), third.extrapolate(-1)), (Ok(68), Ok(101), Ok(5)));
+    assert_eq!(oasis.sum_of_extrapolations(0), Ok(15 + 21 + 45));
+
+    // x^2 / 2 - x / 2, whose Newton form needs fractions.
+    let triangular = History(vec![0, 0, 1, 3, 6, 10]);
+    assert_eq!((triangular.degree(), triangular.extrapolate(10)), (Ok(2), Ok(105)));
+    assert_eq!(History(vec![0, 0, 0]).extrapolate(5), Ok(0));
+
+    let constant = History(vec![7]);
+    assert_eq!((constant.degree(), constant.predict_next(), constant.extrapolate(-3)), (Ok(0), Ok(7), Ok(7)));
+}
+
+#[test]
+fn extrapolation_errors() {
+    assert_eq!(History(vec![1, 2, 4, 8]).predict_next(), Err(ExtrapolationError::NoZeroDifferences));
+    assert_eq!(History(vec![i64::MAX - 3, i64::MAX - 1, i64::MAX, i64::MAX]).degree(), Ok(2));
+    assert_eq!(History(vec![i64::MIN, i64::MAX, i64::MIN]).predict_next(), Err(ExtrapolationError::NoZeroDifferences));
+    assert_eq!(History(vec![0, i64::MAX / 2, i64::MAX - 1]).extrapolate(1), Err(ExtrapolationError::Overflow));
+    assert_eq!(History(vec![0, 1, 2]).extrapolate(i64::MAX), Err(ExtrapolationError::Overflow));
+
+    let oasis = Oasis::parse("0 1 2\n1 2 4 8\n").unwrap();
+    assert_eq!(oasis.sum_of_extrapolations(1).err().unwrap().to_string(), "no answer: history 2: the differences never become all zeros");
+}
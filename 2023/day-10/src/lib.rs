@@ -0,0 +1,422 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use common::{Answer, Direction, Error, ParseError, Point, Solution};
+use graph::Graph;
+use grid::Grid;
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    NorthSouthPipe,
+    EastWestPipe,
+    NorthEastPipe,
+    NorthWestPipe,
+    SouthWestPipe,
+    SouthEastPipe,
+    GroundUndecided,
+    GroundClockwise,
+    GroundCounterClockwise,
+    Start,
+}
+
+impl Tile {
+    fn parse(c: char) -> Option<Self> {
+        match c {
+            '|' => Some(Self::NorthSouthPipe),
+            '-' => Some(Self::EastWestPipe),
+            'L' => Some(Self::NorthEastPipe),
+            'J' => Some(Self::NorthWestPipe),
+            '7' => Some(Self::SouthWestPipe),
+            'F' => Some(Self::SouthEastPipe),
+            '.' => Some(Self::GroundUndecided),
+            'S' => Some(Self::Start),
+            _ => None,
+        }
+    }
+
+    fn to_unicode(self: &Self) -> char {
+        match self {
+            Tile::NorthSouthPipe => '│',
+            Tile::EastWestPipe => '─',
+            Tile::NorthEastPipe => '└',
+            Tile::NorthWestPipe => '┘',
+            Tile::SouthWestPipe => '┐',
+            Tile::SouthEastPipe => '┌',
+            Tile::GroundUndecided => '·',
+            Tile::GroundClockwise => 'C',
+            Tile::GroundCounterClockwise => 'Ɔ',
+            Tile::Start => '┼',
+        }
+    }
+
+    fn openings(self: &Self) -> Vec<Direction> {
+        match self {
+            Tile::NorthSouthPipe => [Direction::North, Direction::South].into(),
+            Tile::EastWestPipe => [Direction::East, Direction::West].into(),
+            Tile::NorthEastPipe => [Direction::North, Direction::East].into(),
+            Tile::NorthWestPipe => [Direction::North, Direction::West].into(),
+            Tile::SouthWestPipe => [Direction::South, Direction::West].into(),
+            Tile::SouthEastPipe => [Direction::South, Direction::East].into(),
+            Tile::GroundUndecided => [].into(),
+            Tile::GroundClockwise => [].into(),
+            Tile::GroundCounterClockwise => [].into(),
+            Tile::Start => Direction::ALL.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TileMap {
+    data: Grid<Tile>,
+}
+
+impl TileMap {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let data = Grid::parse(input, Tile::parse, "a tile out of '|-LJ7F.S'")?;
+
+        let mut start_tiles = input.match_indices('S').map(|(i, _)| &input[i..i + 1]);
+        if start_tiles.next().is_none() {
+            return Err(ParseError::at_end(input, "a start tile 'S'"));
+        }
+        if let Some(second_start) = start_tiles.next() {
+            return Err(ParseError::at(input, second_start, "only one start tile"));
+        }
+
+        Ok(Self { data })
+    }
+
+    fn are_connected(self: &Self, a: Point, b: Point) -> bool {
+        let Some(a_to_b) = Direction::ALL.into_iter().find(|direction| a + direction.to_vector() == b) else {
+            return false;
+        };
+
+        let a_open_to_b = self.data[a]
+            .openings()
+            .contains(&a_to_b);
+
+        let b_open_to_a = self.data[b]
+            .openings()
+            .contains(&a_to_b.opposite());
+
+        a_open_to_b && b_open_to_a
+    }
+
+    fn start(self: &Self) -> Point {
+        let start_tiles: Vec<Point> = self.data
+            .iter()
+            .filter(|(_, tile)| **tile == Tile::Start)
+            .map(|(pos, _)| pos)
+            .collect();
+
+        assert_eq!(start_tiles.len(), 1);
+
+        start_tiles[0]
+    }
+
+    fn connected_neighbors_of(self: &Self, pos: Point) -> Vec<Point> {
+        match self.data.get(pos) {
+            Some(tile) => tile
+                .openings()
+                .iter()
+                .map(|direction| pos + direction.to_vector())
+                .filter(|new_pos| self.data.contains(*new_pos))
+                .filter(|new_pos| self.are_connected(pos, *new_pos))
+                .collect::<Vec<Point>>(),
+
+            None => Vec::new(),
+        }
+    }
+
+    /// Graph of the tiles whose pipes connect.
+    fn pipes(self: &Self) -> Graph<Point> {
+        let mut pipes = Graph::new();
+
+        for pos in self.data.points() {
+            for neighbor in self.connected_neighbors_of(pos) {
+                pipes.add_edge(pos, neighbor, ());
+            }
+        }
+
+        pipes
+    }
+
+    /// The tiles of the loop through the start, in the order they are connected.
+    /// An error if the pipes from the start do not lead back to it.
+    fn trace_loop(self: &Self) -> Result<Vec<Point>, Error> {
+        let pipes = self.pipes();
+        let start = pipes.id(&self.start());
+
+        let n_connected = start.map_or(0, |start| pipes.edges(start).len());
+        let Some(start) = start.filter(|_| n_connected == 2) else {
+            return Err(Error::Unsolvable(format!("the start connects to {n_connected} pipes instead of two")));
+        };
+
+        let pipe_loop: Vec<Point> = pipes.dfs(start)
+            .into_iter()
+            .map(|id| *pipes.key(id))
+            .collect();
+
+        let is_closed = (0..pipe_loop.len()).all(|i| self.are_connected(pipe_loop[i], pipe_loop[(i + 1) % pipe_loop.len()]));
+        match is_closed {
+            true => Ok(pipe_loop),
+            false => Err(Error::Unsolvable("the pipes from the start do not form a loop".to_string())),
+        }
+    }
+}
+
+impl fmt::Display for TileMap {
+    /// The tiles drawn with box-drawing characters.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.data.render(|tile| tile.to_unicode()))
+    }
+}
+
+pub fn solve_part_1(tile_map: &TileMap) -> Result<i32, Error> {
+    let pipe_loop = tile_map.trace_loop()?;
+
+    if !pipe_loop.len().is_multiple_of(2) {
+        return Err(Error::Unsolvable(format!("a loop of {} tiles has no farthest tile", pipe_loop.len())));
+    }
+    Ok((pipe_loop.len() / 2) as i32)
+}
+
+pub fn solve_part_2(tile_map: &TileMap) -> Result<usize, Error> {
+    let mut tile_map = tile_map.clone();
+    let pipe_loop: HashMap<Point, usize> = tile_map.trace_loop()?
+        .into_iter()
+        .enumerate()
+        .map(|(i, pos)| (pos, i))
+        .collect();
+
+    let collect_region_around = |tiles: &Grid<Tile>, pos: Point| -> HashSet<Point> {
+        let mut collected: HashSet<Point> = HashSet::new();
+        let mut horizon: Vec<Point> = [pos].into();
+
+        while let Some(curr_pos) = horizon.pop() {
+            if !pipe_loop.contains_key(&curr_pos) && collected.insert(curr_pos) {
+                horizon.extend(tiles.neighbours_8(curr_pos));
+            }
+        }
+
+        collected
+    };
+
+    let search_space: Vec<Point> = tile_map.data.points().collect();
+    for curr_pos in search_space {
+        if pipe_loop.contains_key(&curr_pos) || [Tile::GroundClockwise, Tile::GroundCounterClockwise].contains(&tile_map.data[curr_pos]) {
+            continue;
+        }
+
+        let mut loop_indices_in_clockwise_surrounding: Vec<usize> = tile_map.data
+            .neighbours_8(curr_pos)
+            .filter_map(|pos| pipe_loop.get(&pos).copied())
+            .collect();
+
+        if loop_indices_in_clockwise_surrounding.len() <= 2 {
+            continue;
+        }
+
+        let is_clockwise_aligned = loop {
+            let all_accenting = loop_indices_in_clockwise_surrounding
+                .windows(2)
+                .all(|w| w[0] < w[1]);
+
+            let all_deccenting = loop_indices_in_clockwise_surrounding
+                .windows(2)
+                .all(|w| w[0] > w[1]);
+
+            if !all_accenting && !all_deccenting {
+                loop_indices_in_clockwise_surrounding.rotate_left(1);
+                continue;
+            }
+
+            break all_accenting;
+        };
+
+        let ground_type = if is_clockwise_aligned {
+            Tile::GroundClockwise
+        }
+        else {
+            Tile::GroundCounterClockwise
+        };
+
+        collect_region_around(&tile_map.data, curr_pos)
+            .iter()
+            .for_each(|p| tile_map.data[*p] = ground_type);
+    }
+
+    // Tiles on the border are outside the loop unless they belong to it.
+    let (n_rows, n_columns) = (tile_map.data.n_rows() as i64, tile_map.data.n_columns() as i64);
+    let mut outside = tile_map.data
+        .points()
+        .filter(|pos| pos.x == 0 || pos.y == 0 || pos.x == n_columns - 1 || pos.y == n_rows - 1)
+        .filter(|pos| !pipe_loop.contains_key(pos));
+
+    let inside_tile_type = match outside.next().map(|pos| tile_map.data[pos]) {
+        Some(Tile::GroundClockwise) => Tile::GroundCounterClockwise,
+        Some(Tile::GroundCounterClockwise) => Tile::GroundClockwise,
+        Some(_) => return Err(Error::Unsolvable("the tiles outside the loop cannot be told from those inside".to_string())),
+        None => return Ok(tile_map.data.points().filter(|pos| !pipe_loop.contains_key(pos)).count()),
+    };
+
+    Ok(tile_map.data
+        .iter()
+        .filter(|(_, tile)| **tile == inside_tile_type)
+        .count())
+}
+
+/// Openings of the tile at `pos`, those of the start being towards the pipes it connects to.
+fn effective_openings(tile_map: &TileMap, pos: Point) -> Vec<Direction> {
+    match tile_map.data[pos] {
+        Tile::Start => tile_map
+            .connected_neighbors_of(pos)
+            .into_iter()
+            .filter_map(|neighbor| Direction::ALL.into_iter().find(|direction| pos + direction.to_vector() == neighbor))
+            .collect(),
+        tile => tile.openings(),
+    }
+}
+
+/// Counts the enclosed tiles from the loop alone: the shoelace formula gives
+/// the area of the polygon through the tile centers, and Pick's theorem
+/// `area = interior + boundary / 2 - 1` turns it into the interior tiles.
+pub fn solve_part_2_by_shoelace(tile_map: &TileMap) -> Result<usize, Error> {
+    let pipe_loop = tile_map.trace_loop()?;
+
+    let twice_area: i64 = (0..pipe_loop.len())
+        .map(|i| {
+            let (a, b) = (pipe_loop[i], pipe_loop[(i + 1) % pipe_loop.len()]);
+            a.x * b.y - b.x * a.y
+        })
+        .sum::<i64>()
+        .abs();
+
+    Ok(((twice_area - pipe_loop.len() as i64) / 2 + 1) as usize)
+}
+
+/// Counts the enclosed tiles row by row: a tile is inside if an odd number of
+/// loop tiles open to the north lie left of it, as a ray to the left then
+/// crosses the loop an odd number of times.
+pub fn solve_part_2_by_scanline(tile_map: &TileMap) -> Result<usize, Error> {
+    let pipe_loop: HashSet<Point> = tile_map.trace_loop()?.into_iter().collect();
+
+    let mut n_enclosed = 0;
+    for row in 0..tile_map.data.n_rows() as i64 {
+        let mut is_inside = false;
+
+        for column in 0..tile_map.data.n_columns() as i64 {
+            let pos = Point::new(column, row);
+
+            if !pipe_loop.contains(&pos) {
+                n_enclosed += is_inside as usize;
+            }
+            else if effective_openings(tile_map, pos).contains(&Direction::North) {
+                is_inside = !is_inside;
+            }
+        }
+    }
+
+    Ok(n_enclosed)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    type Input = TileMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        TileMap::parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_1(input)?.into())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_2(input)?.into())
+    }
+}
+
+#[test]
+fn example_1() {
+    let result = solve_part_1(&TileMap::parse(include_str!("../res/example_1")).unwrap());
+    assert_eq!(result, Ok(8));
+}
+
+#[test]
+fn example_2() {
+    let result = solve_part_2(&TileMap::parse(include_str!("../res/example_2")).unwrap());
+    assert_eq!(result, Ok(4))
+}
+
+#[test]
+fn example_3() {
+    let result = solve_part_2(&TileMap::parse(include_str!("../res/example_3")).unwrap());
+    assert_eq!(result, Ok(8))
+}
+
+#[test]
+fn example_4() {
+    let result = solve_part_2(&TileMap::parse(include_str!("../res/example_4")).unwrap());
+    assert_eq!(result, Ok(10))
+}
+
+#[test]
+fn solvers_agree_on_examples() {
+    let examples = [
+        (include_str!("../res/example_1"), 1),
+        (include_str!("../res/example_2"), 4),
+        (include_str!("../res/example_3"), 8),
+        (include_str!("../res/example_4"), 10),
+    ];
+
+    for (input, expected) in examples {
+        let tile_map = TileMap::parse(input).unwrap();
+
+        assert_eq!(solve_part_2(&tile_map), Ok(expected), "{input}");
+        assert_eq!(solve_part_2_by_shoelace(&tile_map), Ok(expected), "{input}");
+        assert_eq!(solve_part_2_by_scanline(&tile_map), Ok(expected), "{input}");
+    }
+}
+
+#[test]
+fn loop_through_the_corner() {
+    let tile_map = TileMap::parse("S-7\n|.|\nL-J\n").unwrap();
+
+    assert_eq!(tile_map.to_string(), "┼─┐\n│·│\n└─┘\n");
+    assert_eq!(solve_part_2(&tile_map), Ok(1));
+    assert_eq!(solve_part_2_by_shoelace(&tile_map), Ok(1));
+    assert_eq!(solve_part_2_by_scanline(&tile_map), Ok(1));
+}
+
+#[test]
+fn start_without_two_pipes() {
+    for (input, n_connected) in [("S.\n..\n", 0), ("S-7\n|.|\nL-J\n", 2), (".|.\n-S-\n.|.\n", 4)] {
+        let result = solve_part_1(&TileMap::parse(input).unwrap());
+
+        match n_connected {
+            2 => assert_eq!(result, Ok(4)),
+            _ => assert_eq!(result, Err(Error::Unsolvable(format!("the start connects to {n_connected} pipes instead of two")))),
+        }
+    }
+}
+
+#[test]
+fn pipes_that_do_not_close() {
+    let tile_map = TileMap::parse("S--\n|..\n|..\n").unwrap();
+    let error = Err(Error::Unsolvable("the pipes from the start do not form a loop".to_string()));
+
+    assert_eq!(solve_part_2(&tile_map), error);
+    assert_eq!(solve_part_2_by_shoelace(&tile_map), error);
+    assert_eq!(solve_part_2_by_scanline(&tile_map), error);
+}
+
+#[test]
+fn parse_error_position() {
+    let error = TileMap::parse(".S-7.\n.|.|.\n.L-X.\n").err().unwrap();
+    assert_eq!(error.to_string(), "3:4: expected a tile out of '|-LJ7F.S', found 'X'");
+}
@@ -0,0 +1,126 @@
+#![allow(clippy::ptr_arg)]
+
+use common::{Answer, Error, ParseError, Point, Solution};
+use grid::Grid;
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+const EMPTY_SPACE_SYMBOL: char = '.';
+const GALAXY_SYMBOL: char = '#';
+
+fn expension_corrected_index(index: i64, empty_indices: &Vec<i64>, expension_factor: i64) -> i64 {
+    index + (expension_factor - 1) * empty_indices
+        .iter()
+        .filter(|empty_index| **empty_index < index)
+        .count() as i64
+}
+
+pub type Image = Grid<char>;
+
+struct GalaxyPositions (Vec<Point>);
+
+impl GalaxyPositions {
+    fn new(image: &Image, expension_factor: i64) -> Self {
+        let empty_rows: Vec<i64> = image
+            .rows()
+            .enumerate()
+            .filter(|(_, row)| row.iter().all(|c| *c == EMPTY_SPACE_SYMBOL))
+            .map(|(y, _)| y as i64)
+            .collect();
+
+        let empty_columns: Vec<i64> = (0..image.n_columns())
+            .filter(|x| image.column(*x).all(|c| *c == EMPTY_SPACE_SYMBOL))
+            .map(|x| x as i64)
+            .collect();
+
+        let positions: Vec<Point> = image
+            .iter()
+            .filter(|(_, c)| **c == GALAXY_SYMBOL)
+            .map(|(point, _)| Point::new(
+                expension_corrected_index(point.x, &empty_columns, expension_factor),
+                expension_corrected_index(point.y, &empty_rows, expension_factor),
+            ))
+            .collect();
+
+        Self(positions)
+    }
+}
+
+/// Parses a rectangular image of empty space and at least one galaxy.
+pub fn parse_image(input: &str) -> Result<Image, ParseError> {
+    let image = Grid::parse(
+        input,
+        |c| [EMPTY_SPACE_SYMBOL, GALAXY_SYMBOL].contains(&c).then_some(c),
+        &format!("'{EMPTY_SPACE_SYMBOL}' or '{GALAXY_SYMBOL}'"),
+    )?;
+
+    if !input.contains(GALAXY_SYMBOL) {
+        return Err(ParseError::at_end(input, "a galaxy"));
+    }
+
+    Ok(image)
+}
+
+pub fn common_solve(image: &Image, expension_factor: i64) -> u64 {
+    let galaxy_positions = GalaxyPositions::new(image, expension_factor);
+
+    let galaxy_pairs = (0..galaxy_positions.0.len()-1)
+        .flat_map(|lhs_i| {
+            let mut tmp = Vec::new();
+
+            for rhs_i in lhs_i+1..galaxy_positions.0.len() {
+                tmp.push((lhs_i, rhs_i));
+            }
+
+            tmp
+        })
+        .collect::<Vec<(usize, usize)>>();
+
+    galaxy_pairs
+        .iter()
+        .map(|(lhs_i, rhs_i)| (
+            galaxy_positions.0.get(*lhs_i).unwrap(),
+            galaxy_positions.0.get(*rhs_i).unwrap(),
+        ))
+        .map(|(lhs, rhs)| lhs.manhattan_distance(*rhs))
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    type Input = Image;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_image(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(common_solve(input, 2).into())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(common_solve(input, 1000000).into())
+    }
+}
+
+#[test]
+fn example_1() {
+    let result = common_solve(&parse_image(include_str!("../res/example")).unwrap(), 2);
+    assert_eq!(result, 374);
+}
+
+#[test]
+fn example_2() {
+    let result = common_solve(&parse_image(include_str!("../res/example")).unwrap(), 10);
+    assert_eq!(result, 1030);
+}
+
+#[test]
+fn example_3() {
+    let result = common_solve(&parse_image(include_str!("../res/example")).unwrap(), 100);
+    assert_eq!(result, 8410);
+}
@@ -0,0 +1,115 @@
+#![allow(clippy::needless_return)]
+
+use std::collections::HashMap;
+use common::combinator::{lines, number, parse_all, separated_pair, spaces};
+use common::{Answer, Error, ParseError, Solution};
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+pub fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    let parsed_input : Vec<(i32, i32)> = parse_all(input, lines(separated_pair(number(), spaces(), number())))?;
+
+    let list_0 : Vec<i32> = {
+        let mut list = parsed_input
+            .iter()
+            .map(|pair| pair.0)
+            .collect::<Vec<i32>>()
+        ;
+        
+        list.sort();
+
+        list
+    };
+
+    let list_1 : Vec<i32> = {
+        let mut list = parsed_input
+            .iter()
+            .map(|pair| pair.1)
+            .collect::<Vec<i32>>()
+        ;
+        
+        list.sort();
+
+        list
+    };
+
+    assert_eq!(list_0.len(), list_1.len());
+
+    Ok((list_0, list_1))
+}
+
+pub fn solve_part_1((list_0, list_1): &(Vec<i32>, Vec<i32>)) -> i32 {
+    let distances : Vec<i32> = (0..list_0.len())
+        .map(|i| (list_0.get(i).unwrap() - list_1.get(i).unwrap()).abs())
+        .collect()
+    ;
+
+    let total_distance : i32 = distances
+        .iter()
+        .sum()
+    ;
+
+    return total_distance;
+}
+
+pub fn solve_part_2((list_0, list_1): &(Vec<i32>, Vec<i32>)) -> i32 {
+    fn count_id_occurrences(list: &Vec<i32>) -> HashMap<i32, i32> {
+        let mut map = HashMap::new();
+
+        for value in list {
+            map
+                .entry(*value)
+                .and_modify(|v| *v += 1)
+                .or_insert(1)
+            ;
+        }
+
+        map
+    }
+
+    let map_1 : HashMap<i32, i32> = count_id_occurrences(list_1);
+
+    let result : i32 = list_0
+        .iter()
+        .map(|v| {
+            v * map_1.get(v).unwrap_or(&0)
+        })
+        .sum()
+    ;
+
+    result
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+    const COLLAPSE_WHITESPACE: bool = true;
+
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_1(input).into())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_2(input).into())
+    }
+}
+
+#[test]
+fn example_part_1() {
+    let result = solve_part_1(&parse_input(include_str!("../res/example")).unwrap());
+    assert_eq!(result, 11);
+}
+
+#[test]
+fn example_part_2() {
+    let result = solve_part_2(&parse_input(include_str!("../res/example")).unwrap());
+    assert_eq!(result, 31);
+}
@@ -0,0 +1,104 @@
+#![allow(clippy::let_and_return)]
+
+use common::combinator::{lines, map, numbers, parse_all, Parser};
+use common::{Answer, Error, ParseError, Solution};
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    levels: Vec<i32>,
+}
+
+impl Report {
+    /// Levels separated by spaces, e.g. `7 6 4 2 1`.
+    fn parser<'a>() -> impl Parser<'a, Report> {
+        map(numbers(), |levels| Report { levels })
+    }
+
+    fn is_safe(self: &Self) -> bool {
+        let changes : Vec<i32> = self.levels
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .collect()
+        ;
+
+        let all_positive = changes.iter().all(|c| c.is_positive());
+        let all_negative = changes.iter().all(|c| c.is_negative());
+        let all_moderate = changes.iter().all(|c| c.abs() >= 1 && c.abs() <= 3);
+
+        (all_positive || all_negative) && all_moderate
+    }
+
+    fn is_safe_v2(self: &Self) -> bool {
+        let any_simplified_report_safe = (0..self.levels.len())
+            .any(|i| {
+                let mut copy = self.clone();
+                copy.levels.remove(i);
+
+                copy.is_safe()
+            })
+        ;
+        
+        any_simplified_report_safe
+    }
+}
+
+pub fn parse_reports(input: &str) -> Result<Vec<Report>, ParseError> {
+    parse_all(input, lines(Report::parser()))
+}
+
+pub fn solve_part_1(reports: &[Report]) -> usize {
+    let n_safe : usize = reports
+        .iter()
+        .map(|r| r.is_safe())
+        .filter(|flag| *flag)
+        .count()
+    ;
+
+    n_safe
+}
+
+pub fn solve_part_2(reports: &[Report]) -> usize {
+    let n_safe : usize = reports
+        .iter()
+        .map(|r| r.is_safe_v2())
+        .filter(|flag| *flag)
+        .count()
+    ;
+
+    n_safe
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Report>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_reports(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_1(input).into())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_2(input).into())
+    }
+}
+
+#[test]
+fn example_part_1() {
+    let result = solve_part_1(&parse_reports(include_str!("../res/example")).unwrap());
+    assert_eq!(result, 2);
+}
+
+#[test]
+fn example_part_2() {
+    let result = solve_part_2(&parse_reports(include_str!("../res/example")).unwrap());
+    assert_eq!(result, 4);
+}
@@ -0,0 +1,93 @@
+use common::combinator::{delimited, find_all, map, number, or, separated_pair, spanned, tag, verify, Parser};
+use common::{Answer, Error, ParseError, Solution};
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+impl Instruction {
+    /// `mul(<a>,<b>)` with numbers of one to three digits, `do()` or `don't()`.
+    fn parser<'a>() -> impl Parser<'a, Instruction> {
+        let operand = || map(
+            verify(spanned(number::<i32>()), |(_, digits)| (1..=3).contains(&digits.len()) && !digits.starts_with('-'), "a number of 1 to 3 digits"),
+            |(value, _)| value,
+        );
+        let mul = map(
+            delimited(tag("mul("), separated_pair(operand(), tag(","), operand()), tag(")")),
+            |(lhs, rhs)| Instruction::Mul(lhs, rhs),
+        );
+
+        or(mul, or(map(tag("do()"), |_| Instruction::Do), map(tag("don't()"), |_| Instruction::Dont)))
+    }
+}
+
+/// The instructions of the corrupted memory, skipping everything else.
+pub fn parse_instructions(input: &str) -> Vec<Instruction> {
+    find_all(input, Instruction::parser())
+}
+
+pub fn solve_part_1(instructions: &[Instruction]) -> i32 {
+    instructions
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Mul(lhs, rhs) => lhs * rhs,
+            _ => 0,
+        })
+        .sum()
+}
+
+pub fn solve_part_2(instructions: &[Instruction]) -> i32 {
+    let mut is_enalbed = true;
+
+    instructions
+        .iter()
+        .map(|instruction| {
+            match instruction {
+                Instruction::Mul(lhs, rhs) if is_enalbed => return lhs * rhs,
+                Instruction::Mul(_, _) => (),
+                Instruction::Do => is_enalbed = true,
+                Instruction::Dont => is_enalbed = false,
+            }
+
+            0
+        })
+        .sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_instructions(input))
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_1(input).into())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_2(input).into())
+    }
+}
+
+#[test]
+fn example_part_1() {
+    let result = solve_part_1(&parse_instructions(include_str!("../res/example_part_1")));
+    assert_eq!(result, 161);
+}
+
+#[test]
+fn example_part_2() {
+    let result = solve_part_2(&parse_instructions(include_str!("../res/example_part_2")));
+    assert_eq!(result, 48);
+}
@@ -0,0 +1,115 @@
+use common::{Answer, Error, ParseError, Point, Solution, Vector};
+use grid::Grid;
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+#[derive(Debug)]
+pub struct WordSearch(Grid<char>);
+
+impl WordSearch {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(WordSearch(Grid::parse_chars(input)?))
+    }
+
+    fn is_char_at(self: &Self, expected_c: char, point: Point) -> bool {
+        self.0.get(point) == Some(&expected_c)
+    }
+
+    fn trace_xmas(self: &Self, start: Point, step: Vector) -> bool {
+        ['X', 'M', 'A', 'S']
+            .iter()
+            .zip(0..)
+            .all(|(expected_c, k)| self.is_char_at(*expected_c, start + step * k))
+    }
+
+    fn trace_x_mas(self: &Self, center: Point) -> bool {
+        let center_fits = self.is_char_at('A', center);
+
+        // variations
+        //////////////////////////////////////
+        // M   M // M   S // S   S // S   M //
+        //   A   //   A   //   A   //   A   //
+        // S   S // M   S // M   M // S   M //
+        //////////////////////////////////////
+        let any_variation_fits = [
+            [('M', Vector::NORTH_WEST), ('M', Vector::NORTH_EAST), ('S', Vector::SOUTH_WEST), ('S', Vector::SOUTH_EAST)],
+            [('M', Vector::NORTH_WEST), ('S', Vector::NORTH_EAST), ('M', Vector::SOUTH_WEST), ('S', Vector::SOUTH_EAST)],
+            [('S', Vector::NORTH_WEST), ('S', Vector::NORTH_EAST), ('M', Vector::SOUTH_WEST), ('M', Vector::SOUTH_EAST)],
+            [('S', Vector::NORTH_WEST), ('M', Vector::NORTH_EAST), ('S', Vector::SOUTH_WEST), ('M', Vector::SOUTH_EAST)],
+        ]
+            .iter()
+            .any(|variations| variations
+                .iter()
+                .all(|(c, step)| self.is_char_at(*c, center + *step))
+            )
+        ;
+
+        center_fits && any_variation_fits
+    }
+}
+
+pub fn solve_part_1(word_search: &WordSearch) -> usize {
+    let mut n_xmas_occurrences = 0;
+
+    for start in word_search.0.points() {
+
+        // traces
+        ////////////////////
+        //  S     S     S //
+        //    A   A   A   //
+        //      M M M     //
+        //  S A M X M A S //
+        //      M M M     //
+        //    A   A   A   //
+        //  S     S     S //
+        ////////////////////
+        let n_traces = Vector::AROUND
+            .iter()
+            .filter(|step| word_search.trace_xmas(start, **step))
+            .count()
+        ;
+        n_xmas_occurrences += n_traces;
+    }
+
+    n_xmas_occurrences
+}
+
+pub fn solve_part_2(word_search: &WordSearch) -> usize {
+    word_search.0
+        .points()
+        .filter(|center| word_search.trace_x_mas(*center))
+        .count()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Input = WordSearch;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        WordSearch::parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_1(input).into())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_2(input).into())
+    }
+}
+
+#[test]
+fn example_part_1() {
+    let result = solve_part_1(&WordSearch::parse(include_str!("../res/example")).unwrap());
+    assert_eq!(result, 18);
+}
+
+#[test]
+fn example_part_2() {
+    let result = solve_part_2(&WordSearch::parse(include_str!("../res/example")).unwrap());
+    assert_eq!(result, 9);
+}
@@ -0,0 +1,270 @@
+use std::fmt;
+use common::combinator::{comma_separated, lines, map, number, parse_all, separated_pair, tag, verify, Parser};
+use common::{Answer, Error, ParseError, Solution};
+use graph::Graph;
+
+pub const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res");
+
+/// Graph of the pages with an edge from each page to the pages that have to follow it.
+#[derive(Debug)]
+pub struct OrderingRules(Graph<i32>);
+
+impl OrderingRules {
+    /// One rule `<page>|<page>` per line.
+    fn parser<'a>() -> impl Parser<'a, Self> {
+        map(lines(separated_pair(number::<i32>(), tag("|"), number::<i32>())), |rules| {
+            Self(rules.into_iter().collect())
+        })
+    }
+
+    fn is_ordered_pair(self: &Self, lhs: i32, rhs: i32) -> bool {
+        match (self.0.id(&lhs), self.0.id(&rhs)) {
+            (Some(lhs), Some(rhs)) => self.0.has_edge(lhs, rhs),
+            _ => false,
+        }
+    }
+
+    /// Checks every pair of pages of `page_update` against the rules.
+    pub fn validate(self: &Self, page_update: &[i32]) -> Validation {
+        let mut validation = Validation::default();
+
+        for (i, earlier) in page_update.iter().enumerate() {
+            for later in &page_update[i + 1..] {
+                if self.is_ordered_pair(*earlier, *later) {
+                    continue;
+                }
+
+                match self.is_ordered_pair(*later, *earlier) {
+                    true => validation.violated.push((*later, *earlier)),
+                    false => validation.unconstrained.push((*earlier, *later)),
+                }
+            }
+        }
+
+        validation
+    }
+
+    fn is_correctly_ordered(self: &Self, page_update: &[i32]) -> bool {
+        self.validate(page_update).is_ordered()
+    }
+
+    /// The pages of `page_update` sorted topologically by the rules between
+    /// them. Other pages may be unordered, but the middle one must follow from
+    /// the rules, directly or through other pages.
+    fn reorder(self: &Self, page_update: &[i32]) -> Result<Vec<i32>, Error> {
+        let update = page_update.iter().map(|page| page.to_string()).collect::<Vec<String>>().join(",");
+        let rules = self.0.induced_subgraph(page_update);
+
+        if rules.len() != page_update.len() {
+            return Err(Error::Unsolvable(format!("update {update} lists a page more than once")));
+        }
+
+        match rules.topological_sort() {
+            Ok(order) => {
+                let middle = order.len() / 2;
+                let after_middle = rules.bfs(order[middle]);
+                let unordered = (0..order.len())
+                    .find(|i| after_middle[order[*i]].is_none() && rules.bfs(order[*i])[order[middle]].is_none());
+
+                match unordered {
+                    Some(i) => Err(Error::Unsolvable(format!(
+                        "no rule orders {} and {} in update {update}",
+                        rules.key(order[i.min(middle)]), rules.key(order[i.max(middle)])
+                    ))),
+                    None => Ok(order.into_iter().map(|id| *rules.key(id)).collect()),
+                }
+            },
+            Err(cycle) => {
+                let cycle: Vec<String> = (0..cycle.len())
+                    .map(|i| format!("{}|{}", rules.key(cycle[i]), rules.key(cycle[(i + 1) % cycle.len()])))
+                    .collect();
+                Err(Error::Unsolvable(format!("the rules for update {update} form the cycle {}", cycle.join(", "))))
+            },
+        }
+    }
+}
+
+/// How the pages of an update relate to the rules.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Validation {
+    /// Rules `<before>|<after>` whose pages the update lists the other way around.
+    pub violated: Vec<(i32, i32)>,
+    /// Pairs of pages, in update order, that no rule orders.
+    pub unconstrained: Vec<(i32, i32)>,
+}
+
+impl Validation {
+    /// No rule is violated; pages without a rule between them may come in any order.
+    pub fn is_ordered(self: &Self) -> bool {
+        self.violated.is_empty()
+    }
+}
+
+impl fmt::Display for Validation {
+    /// E.g. `breaks 97|75; no rule orders 61,13`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pairs = |pairs: &[(i32, i32)], separator: &str| {
+            pairs.iter().map(|(a, b)| format!("{a}{separator}{b}")).collect::<Vec<String>>().join(", ")
+        };
+
+        let mut findings: Vec<String> = Vec::new();
+        if !self.violated.is_empty() {
+            findings.push(format!("breaks {}", pairs(&self.violated, "|")));
+        }
+        if !self.unconstrained.is_empty() {
+            findings.push(format!("no rule orders {}", pairs(&self.unconstrained, ",")));
+        }
+
+        match findings.is_empty() {
+            true => write!(f, "ordered"),
+            false => write!(f, "{}", findings.join("; ")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PageUpdates(Vec<Vec<i32>>);
+
+impl PageUpdates {
+    /// One update `<page>,<page>,...` of an odd number of pages per line.
+    fn parser<'a>() -> impl Parser<'a, Self> {
+        let update = verify(comma_separated(number::<i32>()), |update| update.len() % 2 == 1, "an odd number of pages");
+
+        map(lines(update), PageUpdates)
+    }
+
+    pub fn updates(self: &Self) -> &[Vec<i32>] {
+        &self.0
+    }
+
+    fn sum_middle_page_numbers(self: &Self) -> i32 {
+        self.0
+            .iter()
+            .map(|page_update| {
+                assert!(page_update.len() % 2 != 0);
+                let middle_index = page_update.len() / 2;
+                page_update.get(middle_index).unwrap()
+            })
+            .sum()
+    }
+}
+
+pub fn parse(input: &str) -> Result<(OrderingRules, PageUpdates), ParseError> {
+    parse_all(input, separated_pair(OrderingRules::parser(), tag("\n"), PageUpdates::parser()))
+}
+
+pub fn solve_part_1(ordering_rules: &OrderingRules, page_updates: &PageUpdates) -> i32 {
+    let correctly_ordered_updates = PageUpdates(
+        page_updates.0
+            .iter()
+            .filter(|page_update| ordering_rules.is_correctly_ordered(page_update))
+            .cloned()
+            .collect::<Vec<Vec<i32>>>()
+    );
+
+    correctly_ordered_updates.sum_middle_page_numbers()
+}
+
+pub fn solve_part_2(ordering_rules: &OrderingRules, page_updates: &PageUpdates) -> Result<i32, Error> {
+    let reordered_updates = PageUpdates(
+        page_updates.0
+            .iter()
+            .filter(|page_update| !ordering_rules.is_correctly_ordered(page_update))
+            .map(|page_update| ordering_rules.reorder(page_update))
+            .collect::<Result<Vec<Vec<i32>>, Error>>()?
+    );
+
+    Ok(reordered_updates.sum_middle_page_numbers())
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type Input = (OrderingRules, PageUpdates);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1((ordering_rules, page_updates): &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_1(ordering_rules, page_updates).into())
+    }
+
+    fn part_2((ordering_rules, page_updates): &Self::Input) -> Result<Answer, Error> {
+        Ok(solve_part_2(ordering_rules, page_updates)?.into())
+    }
+}
+
+#[test]
+fn example() {
+    let (ordering_rules, page_updates) = parse(include_str!("../res/example")).unwrap();
+    let result = solve_part_1(&ordering_rules, &page_updates);
+    assert_eq!(result, 143);
+}
+
+#[test]
+fn example_part_2() {
+    let (ordering_rules, page_updates) = parse(include_str!("../res/example")).unwrap();
+    let result = solve_part_2(&ordering_rules, &page_updates);
+    assert_eq!(result, Ok(123));
+}
+
+#[test]
+fn validate_updates() {
+    let (ordering_rules, page_updates) = parse(include_str!("../res/example")).unwrap();
+
+    let reports: Vec<String> = page_updates
+        .updates()
+        .iter()
+        .map(|page_update| ordering_rules.validate(page_update).to_string())
+        .collect();
+
+    assert_eq!(reports, [
+        "ordered",
+        "ordered",
+        "ordered",
+        "breaks 97|75",
+        "breaks 29|13",
+        "breaks 75|13, 29|13, 47|13, 47|29",
+    ]);
+
+    let (ordering_rules, _) = parse("47|53\n53|13\n47|13\n\n1\n").unwrap();
+    let validation = ordering_rules.validate(&[13, 47, 61]);
+    assert_eq!(validation, Validation { violated: vec![(47, 13)], unconstrained: vec![(13, 61), (47, 61)] });
+    assert_eq!(validation.to_string(), "breaks 47|13; no rule orders 13,61, 47,61");
+    assert!(ordering_rules.validate(&[47, 61, 53]).is_ordered());
+}
+
+#[test]
+fn cyclic_rules() {
+    let (ordering_rules, page_updates) = parse("47|53\n53|61\n61|47\n13|47\n\n47,53,13\n61,53,47\n").unwrap();
+    let error = solve_part_2(&ordering_rules, &page_updates).err().unwrap();
+    assert_eq!(error.to_string(), "no answer: the rules for update 61,53,47 form the cycle 61|47, 47|53, 53|61");
+
+    let (ordering_rules, page_updates) = parse("47|53\n\n53,47,53\n").unwrap();
+    let error = solve_part_2(&ordering_rules, &page_updates).err().unwrap();
+    assert_eq!(error.to_string(), "no answer: update 53,47,53 lists a page more than once");
+}
+
+#[test]
+fn ambiguous_order() {
+    let (ordering_rules, page_updates) = parse("47|53\n47|61\n\n53,61,47\n").unwrap();
+    let error = solve_part_2(&ordering_rules, &page_updates).err().unwrap();
+    assert_eq!(error.to_string(), "no answer: no rule orders 53 and 61 in update 53,61,47");
+
+    // Neither 1 and 2 nor 4 and 5 are ordered, but 3 is the middle page either way.
+    let (ordering_rules, page_updates) = parse("1|3\n2|3\n3|4\n3|5\n\n5,4,3,2,1\n").unwrap();
+    assert_eq!(solve_part_2(&ordering_rules, &page_updates), Ok(3));
+}
+
+#[test]
+fn parse_error_position() {
+    let error = parse("47|53\n97|x\n\n75,47,61\n").err().unwrap();
+    assert_eq!(error.to_string(), "2:4: expected a number, found 'x'");
+
+    let error = parse("47|53\n\n75,47\n").err().unwrap();
+    assert_eq!(error.to_string(), "3:1: expected an odd number of pages, found '75,47'");
+}
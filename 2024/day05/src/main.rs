@@ -1,93 +1,52 @@
-use std::collections::{HashMap, HashSet};
-
-#[derive(Debug)]
-struct OrderingRules(HashMap<i32, HashSet<i32>>);
-
-impl OrderingRules {
-    fn from(input: &str) -> Self {
-        let mut rules_map: HashMap<i32, HashSet<i32>> = HashMap::new();
+use std::path::Path;
+use std::process::ExitCode;
+use common::{normalize, RunArgs, Solution, INPUT_USAGE};
+use aoc_2024_day05::{Day05, RESOURCES};
+
+const USAGE: &str = "\
+usage: [--part <1|2>] [input selection]
+       validate [--all] [input selection]";
+
+/// Prints the rules that each update breaks, and pairs of pages no rule orders.
+fn validate(args: &[String]) -> Result<(), String> {
+    let show_all = args.iter().any(|arg| arg == "--all");
+    let args: Vec<String> = args.iter().filter(|arg| *arg != "--all").cloned().collect();
+    let run_args = RunArgs::parse(&args)?;
+
+    if let Some(unexpected) = run_args.positional.first() {
+        return Err(format!("unexpected argument '{unexpected}'"));
+    }
 
-        for line in input.lines() {
-            let split_line = line.split('|').collect::<Vec<&str>>();
-            assert_eq!(split_line.len(), 2);
+    let input = run_args.source
+        .read(Path::new(RESOURCES))
+        .map_err(|error| error.to_string())?;
+    let (ordering_rules, page_updates) = Day05::parse(&normalize(&input, Day05::COLLAPSE_WHITESPACE))
+        .map_err(|error| error.with_input_name(run_args.source.name()).to_string())?;
 
-            let lhs = split_line.get(0).unwrap().parse::<i32>().unwrap();
-            let rhs = split_line.get(1).unwrap().parse::<i32>().unwrap();
+    for (i, page_update) in page_updates.updates().iter().enumerate() {
+        let validation = ordering_rules.validate(page_update);
 
-            rules_map.entry(lhs).or_insert([rhs].into()).insert(rhs);
+        if show_all || !validation.is_ordered() {
+            let update: Vec<String> = page_update.iter().map(|page| page.to_string()).collect();
+            println!("update {} ({}): {validation}", i + 1, update.join(","));
         }
-
-        Self(rules_map)
     }
 
-    fn is_ordered_pair(self: &Self, lhs: i32, rhs: i32) -> bool {
-        self.0.contains_key(&lhs) && self.0.get(&lhs).unwrap().contains(&rhs)
-    }
+    Ok(())
 }
 
-#[derive(Debug)]
-struct PageUpdates(Vec<Vec<i32>>);
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
 
-impl PageUpdates {
-    fn from(input: &str) -> Self {
-        PageUpdates(
-            input
-                .lines()
-                .map(|update_string| {
-                    update_string
-                        .split(',')
-                        .map(|page_id_string| page_id_string.parse::<i32>().unwrap())
-                        .collect()
-                })
-                .collect()
-        )
+    if args.first().map(String::as_str) != Some("validate") {
+        return common::main(&Day05, RESOURCES);
     }
 
-    fn sum_middle_page_numbers(self: &Self) -> i32 {
-        self.0
-            .iter()
-            .map(|page_update| {
-                assert!(page_update.len() % 2 != 0);
-                let middle_index = page_update.len() / 2;
-                page_update.get(middle_index).unwrap()
-            })
-            .sum()
+    match validate(&args[1..]) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}\n{USAGE}\n{INPUT_USAGE}");
+            ExitCode::FAILURE
+        },
     }
 }
-
-fn solve(input: &str) -> i32 {
-    let input_sections = input.split("\n\n").collect::<Vec<&str>>();
-    assert_eq!(input_sections.len(), 2);
-
-    let ordering_rules = OrderingRules::from(input_sections.get(0).unwrap());
-    let page_updates = PageUpdates::from(input_sections.get(1).unwrap());
-
-    let correctly_ordered_updates = PageUpdates(
-        page_updates.0
-            .iter()
-            .filter(|page_update| page_update
-                .windows(2)
-                .all(|ids| {
-                    assert_eq!(ids.len(), 2);
-                    let lhs = ids.get(0).unwrap();
-                    let rhs = ids.get(1).unwrap();
-                    ordering_rules.is_ordered_pair(*lhs, *rhs)
-                })
-            )
-            .cloned()
-            .collect::<Vec<Vec<i32>>>()
-    );
-
-    correctly_ordered_updates.sum_middle_page_numbers()
-}
-
-#[test]
-fn example() {
-    let result = solve(include_str!("../res/example"));
-    assert_eq!(result, 143);
-}
-
-fn main() {
-    let result = solve(include_str!("../res/input"));
-    println!("result={result}");
-}
@@ -0,0 +1,234 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use common::Part;
+
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt");
+
+const HEADER: &str = "\
+# Accepted answers, one per line: <year> <day> <part> <input> <answer>
+# <input> is the name of the input file, e.g. input, example or example_2.
+# Rejected submissions: ! <year> <day> <part> <input> <too-high|too-low|wrong> <answer>
+";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+}
+
+/// Why the server rejected a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Verdict {
+    pub fn as_str(self: &Self) -> &'static str {
+        match self {
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "too-high" => Some(Verdict::TooHigh),
+            "too-low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            _ => None,
+        }
+    }
+}
+
+/// The answer database: a plain text file so it can be reviewed in diffs.
+///
+/// The default file is checked in; `AOC_ANSWERS` points to a different
+/// (e.g. git-ignored, per account) file.
+#[derive(Debug)]
+pub struct Answers {
+    path: PathBuf,
+    entries: BTreeMap<Key, String>,
+    rejected: BTreeMap<Key, Vec<(String, Verdict)>>,
+}
+
+impl Answers {
+    pub fn default_path() -> PathBuf {
+        match std::env::var_os("AOC_ANSWERS") {
+            Some(path) => PathBuf::from(path),
+            None => PathBuf::from(DEFAULT_PATH),
+        }
+    }
+
+    /// Loads the database; a missing file is an empty database.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(format!("{}: {error}", path.display())),
+        };
+
+        let answers = Self::parse(&content)
+            .map_err(|(line_number, message)| format!("{}:{line_number}: {message}", path.display()))?;
+
+        Ok(Answers { path: path.to_path_buf(), ..answers })
+    }
+
+    fn parse(content: &str) -> Result<Self, (usize, String)> {
+        let mut entries = BTreeMap::new();
+        let mut rejected: BTreeMap<Key, Vec<(String, Verdict)>> = BTreeMap::new();
+
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(line) = line.strip_prefix('!') {
+                let fields: Vec<&str> = line.trim().splitn(6, char::is_whitespace).collect();
+                let [year, day, part, input, verdict, answer] = fields.as_slice() else {
+                    return Err((i + 1, format!("expected ! <year> <day> <part> <input> <verdict> <answer>, got '!{line}'")));
+                };
+
+                let key = Self::parse_key(year, day, part, input).map_err(|message| (i + 1, message))?;
+                let verdict = Verdict::parse(verdict).ok_or((i + 1, format!("invalid verdict '{verdict}'")))?;
+
+                rejected.entry(key).or_default().push((answer.trim().to_string(), verdict));
+                continue;
+            }
+
+            let fields: Vec<&str> = line.splitn(5, char::is_whitespace).collect();
+            let [year, day, part, input, answer] = fields.as_slice() else {
+                return Err((i + 1, format!("expected <year> <day> <part> <input> <answer>, got '{line}'")));
+            };
+
+            let key = Self::parse_key(year, day, part, input).map_err(|message| (i + 1, message))?;
+
+            if entries.insert(key, answer.trim().to_string()).is_some() {
+                return Err((i + 1, "duplicate entry".to_string()));
+            }
+        }
+
+        Ok(Answers { path: PathBuf::new(), entries, rejected })
+    }
+
+    fn parse_key(year: &str, day: &str, part: &str, input: &str) -> Result<Key, String> {
+        Ok(Key {
+            year: year.parse().map_err(|_| format!("invalid year '{year}'"))?,
+            day: day.parse().map_err(|_| format!("invalid day '{day}'"))?,
+            part: part
+                .parse()
+                .ok()
+                .and_then(Part::from_number)
+                .ok_or(format!("invalid part '{part}'"))?,
+            input: input.to_string(),
+        })
+    }
+
+    pub fn get(self: &Self, key: &Key) -> Option<&str> {
+        self.entries.get(key).map(|answer| answer.as_str())
+    }
+
+    pub fn insert(self: &mut Self, key: Key, answer: String) {
+        self.entries.insert(key, answer);
+    }
+
+    /// Names of all inputs with at least one recorded answer for the given day.
+    pub fn inputs_of(self: &Self, year: u16, day: u8) -> BTreeSet<String> {
+        self.entries
+            .keys()
+            .filter(|key| key.year == year && key.day == day)
+            .map(|key| key.input.clone())
+            .collect()
+    }
+
+    /// Answers the server rejected for the given key, in submission order.
+    pub fn rejected(self: &Self, key: &Key) -> &[(String, Verdict)] {
+        self.rejected.get(key).map(|rejected| rejected.as_slice()).unwrap_or(&[])
+    }
+
+    pub fn reject(self: &mut Self, key: Key, answer: String, verdict: Verdict) {
+        self.rejected.entry(key).or_default().push((answer, verdict));
+    }
+
+    /// Refuses answers that are known to be wrong: already rejected ones, ones
+    /// beyond a too high or too low bound, and any when one was accepted.
+    pub fn check_submission(self: &Self, key: &Key, answer: &str) -> Result<(), String> {
+        if let Some(accepted) = self.get(key) {
+            return Err(format!("already solved, the accepted answer is {accepted}"));
+        }
+
+        for (rejected, verdict) in self.rejected(key) {
+            if rejected == answer {
+                return Err(format!("{answer} was already rejected ({})", verdict.as_str()));
+            }
+
+            let bound = match (answer.parse::<i128>(), rejected.parse::<i128>()) {
+                (Ok(answer), Ok(rejected)) => (answer, rejected),
+                _ => continue,
+            };
+
+            match verdict {
+                Verdict::TooHigh if bound.0 > bound.1 => return Err(format!("{answer} is too high, {rejected} already was")),
+                Verdict::TooLow if bound.0 < bound.1 => return Err(format!("{answer} is too low, {rejected} already was")),
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn save(self: &Self) -> Result<(), String> {
+        let mut content = HEADER.to_string();
+
+        for (key, answer) in &self.entries {
+            content += &format!("{} {} {} {} {}\n", key.year, key.day, key.part, key.input, answer);
+        }
+
+        for (key, rejected) in &self.rejected {
+            for (answer, verdict) in rejected {
+                content += &format!("! {} {} {} {} {} {}\n", key.year, key.day, key.part, key.input, verdict.as_str(), answer);
+            }
+        }
+
+        std::fs::write(&self.path, content).map_err(|error| format!("{}: {error}", self.path.display()))
+    }
+}
+
+#[test]
+fn parse_answers() {
+    let answers = Answers::parse("# comment\n\n2023 8 2 input 18024643846273\n2024 3 1 example_part_1 161\n").unwrap();
+
+    let key = Key { year: 2023, day: 8, part: Part::Two, input: "input".to_string() };
+    assert_eq!(answers.get(&key), Some("18024643846273"));
+    assert_eq!(answers.entries.len(), 2);
+}
+
+#[test]
+fn parse_answers_reports_line() {
+    let error = Answers::parse("2023 8 2 input 1\n2023 8 3 input 1\n").unwrap_err();
+    assert_eq!(error.0, 2);
+
+    let error = Answers::parse("2023 8 2 input 1\n2023 8 2 input 2\n").unwrap_err();
+    assert_eq!(error, (2, "duplicate entry".to_string()));
+}
+
+#[test]
+fn known_wrong_answers_are_refused() {
+    let answers = Answers::parse("! 2023 1 1 input too-high 500\n! 2023 1 1 input too-low 100\n! 2023 1 1 input wrong 250\n").unwrap();
+    let key = Key { year: 2023, day: 1, part: Part::One, input: "input".to_string() };
+
+    assert_eq!(answers.rejected(&key).len(), 3);
+    assert!(answers.check_submission(&key, "500").is_err());
+    assert!(answers.check_submission(&key, "501").is_err());
+    assert!(answers.check_submission(&key, "99").is_err());
+    assert!(answers.check_submission(&key, "250").is_err());
+    assert!(answers.check_submission(&key, "300").is_ok());
+
+    let answers = Answers::parse("2023 1 1 input 300\n").unwrap();
+    assert!(answers.check_submission(&key, "300").is_err());
+}
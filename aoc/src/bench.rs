@@ -0,0 +1,277 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use crate::registry::{self, Entry};
+
+pub const DEFAULT_BASELINE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../bench-baseline.json");
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn of(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty());
+
+        let mut sorted: Vec<Duration> = samples.to_vec();
+        sorted.sort();
+
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2
+        }
+        else {
+            sorted[sorted.len() / 2]
+        };
+
+        let mean_secs = samples.iter().map(|d| d.as_secs_f64()).sum::<f64>() / samples.len() as f64;
+        let variance = samples
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>() / samples.len() as f64;
+
+        Stats {
+            mean: Duration::from_secs_f64(mean_secs),
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Key {
+    year: u16,
+    day: u8,
+    phase: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineEntry {
+    year: u16,
+    day: u8,
+    phase: String,
+    mean_ns: u64,
+    median_ns: u64,
+    stddev_ns: u64,
+}
+
+/// Timings of an earlier run to compare against. Timings depend on the machine,
+/// so the file is not checked in; `AOC_BASELINE` points to a different file.
+#[derive(Debug)]
+pub struct Baseline {
+    path: PathBuf,
+    entries: BTreeMap<Key, Stats>,
+}
+
+impl Baseline {
+    pub fn default_path() -> PathBuf {
+        match std::env::var_os("AOC_BASELINE") {
+            Some(path) => PathBuf::from(path),
+            None => PathBuf::from(DEFAULT_BASELINE_PATH),
+        }
+    }
+
+    /// Loads the baseline; a missing file is an empty baseline.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => "[]".to_string(),
+            Err(error) => return Err(format!("{}: {error}", path.display())),
+        };
+
+        let entries = Self::parse(&content).map_err(|error| format!("{}: {error}", path.display()))?;
+
+        Ok(Baseline { path: path.to_path_buf(), entries })
+    }
+
+    fn parse(content: &str) -> Result<BTreeMap<Key, Stats>, serde_json::Error> {
+        let entries: Vec<BaselineEntry> = serde_json::from_str(content)?;
+
+        Ok(entries
+            .into_iter()
+            .map(|entry| {
+                let key = Key { year: entry.year, day: entry.day, phase: entry.phase };
+                let stats = Stats {
+                    mean: Duration::from_nanos(entry.mean_ns),
+                    median: Duration::from_nanos(entry.median_ns),
+                    stddev: Duration::from_nanos(entry.stddev_ns),
+                };
+                (key, stats)
+            })
+            .collect())
+    }
+
+    fn to_json(self: &Self) -> String {
+        let entries: Vec<BaselineEntry> = self.entries
+            .iter()
+            .map(|(key, stats)| BaselineEntry {
+                year: key.year,
+                day: key.day,
+                phase: key.phase.clone(),
+                mean_ns: stats.mean.as_nanos() as u64,
+                median_ns: stats.median.as_nanos() as u64,
+                stddev_ns: stats.stddev.as_nanos() as u64,
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&entries).unwrap() + "\n"
+    }
+
+    pub fn save(self: &Self) -> Result<(), String> {
+        std::fs::write(&self.path, self.to_json()).map_err(|error| format!("{}: {error}", self.path.display()))
+    }
+}
+
+#[derive(Debug)]
+pub struct Options {
+    pub iterations: usize,
+    /// Relative slowdown of the median that counts as a regression, e.g. 0.2 for 20%.
+    pub threshold: f64,
+    pub save_baseline: bool,
+}
+
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+
+    if nanos < 1e3 {
+        format!("{nanos:.0}ns")
+    }
+    else if nanos < 1e6 {
+        format!("{:.1}µs", nanos / 1e3)
+    }
+    else if nanos < 1e9 {
+        format!("{:.2}ms", nanos / 1e6)
+    }
+    else {
+        format!("{:.3}s", nanos / 1e9)
+    }
+}
+
+/// Relative change of the median against the baseline, e.g. 0.25 when 25% slower.
+fn relative_change(stats: &Stats, baseline: &Stats) -> f64 {
+    let baseline_median = baseline.median.as_secs_f64().max(1e-9);
+    stats.median.as_secs_f64() / baseline_median - 1.0
+}
+
+/// Times every phase of one day; returns the stats per phase in execution order.
+fn bench_entry(entry: &Entry, input: &str, iterations: usize) -> Result<Vec<(String, Stats)>, String> {
+    // The warm-up run also makes sure the day solves at all before timing it.
+    let warm_up = entry.puzzle.time_phases(input).map_err(|error| error.with_input_name("input").to_string())?;
+
+    let mut phases: Vec<(String, Vec<Duration>)> = [("parse".to_string(), Vec::new())].into();
+    for (part, _) in &warm_up.parts {
+        phases.push((format!("part_{part}"), Vec::new()));
+    }
+
+    for _ in 0..iterations {
+        let times = entry.puzzle.time_phases(input).map_err(|error| error.to_string())?;
+
+        phases[0].1.push(times.parse);
+        for (i, (_, duration)) in times.parts.iter().enumerate() {
+            phases[i + 1].1.push(*duration);
+        }
+    }
+
+    Ok(phases
+        .into_iter()
+        .map(|(phase, samples)| (phase, Stats::of(&samples)))
+        .collect())
+}
+
+/// Benchmarks every registered solution (optionally only of one year or day) on
+/// its real input and compares the medians against the baseline.
+///
+/// Returns whether no phase regressed.
+pub fn bench(baseline: &mut Baseline, year: Option<u16>, day: Option<u8>, options: &Options) -> Result<bool, String> {
+    let entries = registry::REGISTRY
+        .iter()
+        .filter(|entry| year.is_none_or(|year| entry.puzzle.year() == year))
+        .filter(|entry| day.is_none_or(|day| entry.puzzle.day() == day));
+
+    let mut n_regressions = 0;
+    let mut n_failures = 0;
+
+    for entry in entries {
+        let (year, day) = (entry.puzzle.year(), entry.puzzle.day());
+
+        let Ok(input) = common::read_resource(Path::new(entry.resources), "input") else {
+            println!("{year} day {day:>2}           no input");
+            continue;
+        };
+
+        let phases = match bench_entry(entry, &input, options.iterations) {
+            Ok(phases) => phases,
+            Err(error) => {
+                println!("{year} day {day:>2}           FAIL {error}");
+                n_failures += 1;
+                continue;
+            },
+        };
+
+        for (phase, stats) in phases {
+            let key = Key { year, day, phase };
+
+            let mut line = format!(
+                "{year} day {day:>2} {:<7}  mean {:>9}  median {:>9}  stddev {:>9}",
+                key.phase,
+                format_duration(stats.mean),
+                format_duration(stats.median),
+                format_duration(stats.stddev),
+            );
+
+            if let Some(previous) = baseline.entries.get(&key) {
+                let change = relative_change(&stats, previous);
+                line += &format!("  baseline {:>9} {:>+7.1}%", format_duration(previous.median), change * 100.0);
+
+                if change > options.threshold {
+                    line += "  REGRESSION";
+                    n_regressions += 1;
+                }
+            }
+
+            println!("{line}");
+
+            if options.save_baseline {
+                baseline.entries.insert(key, stats);
+            }
+        }
+    }
+
+    if options.save_baseline {
+        baseline.save()?;
+        println!("\nbaseline saved to {}", baseline.path.display());
+    }
+
+    println!("\n{n_regressions} regressions, {n_failures} failed");
+
+    Ok(n_failures == 0 && (n_regressions == 0 || options.save_baseline))
+}
+
+#[test]
+fn stats_of_samples() {
+    let samples = [3, 1, 4, 1, 5, 2].map(Duration::from_millis);
+    let stats = Stats::of(&samples);
+
+    assert_eq!(stats.median, Duration::from_micros(2500));
+    assert_eq!(stats.mean.as_micros(), 2666);
+    assert_eq!(stats.stddev.as_micros(), 1490);
+}
+
+#[test]
+fn baseline_round_trip() {
+    let content = r#"[{"year": 2023, "day": 10, "phase": "part_2", "mean_ns": 1200, "median_ns": 1000, "stddev_ns": 50}]"#;
+    let entries = Baseline::parse(content).unwrap();
+
+    let key = Key { year: 2023, day: 10, phase: "part_2".to_string() };
+    let previous = entries[&key];
+    assert_eq!(previous.median, Duration::from_nanos(1000));
+
+    let slower = Stats { median: Duration::from_nanos(1300), ..previous };
+    assert!(relative_change(&slower, &previous) > 0.29);
+
+    let baseline = Baseline { path: PathBuf::new(), entries };
+    assert_eq!(Baseline::parse(&baseline.to_json()).unwrap(), baseline.entries);
+}
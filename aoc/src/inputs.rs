@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+use common::{InputKey, ENCRYPTED_SUFFIX};
+
+fn input_paths(resources: &Path) -> (PathBuf, PathBuf) {
+    (resources.join("input"), resources.join(format!("input{ENCRYPTED_SUFFIX}")))
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, String> {
+    std::fs::read(path).map_err(|error| format!("{}: {error}", path.display()))
+}
+
+fn write(path: &Path, content: &[u8]) -> Result<(), String> {
+    std::fs::write(path, content).map_err(|error| format!("{}: {error}", path.display()))
+}
+
+fn remove(path: &Path) -> Result<(), String> {
+    std::fs::remove_file(path).map_err(|error| format!("{}: {error}", path.display()))
+}
+
+/// Replaces every plain `input` in the given resource directories by an
+/// encrypted `input.enc`; returns how many were encrypted.
+pub fn encrypt_all(resources: &[&Path], key: &InputKey) -> Result<usize, String> {
+    let mut count = 0;
+
+    for (plain, encrypted) in resources.iter().map(|resources| input_paths(resources)) {
+        if !plain.exists() {
+            continue;
+        }
+
+        let input = String::from_utf8(read(&plain)?).map_err(|_| format!("{}: not UTF-8", plain.display()))?;
+        write(&encrypted, &key.encrypt(&input))?;
+        remove(&plain)?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Replaces every `input.enc` by the plain `input`; returns how many were decrypted.
+pub fn decrypt_all(resources: &[&Path], key: &InputKey) -> Result<usize, String> {
+    let mut count = 0;
+
+    for (plain, encrypted) in resources.iter().map(|resources| input_paths(resources)) {
+        if !encrypted.exists() {
+            continue;
+        }
+
+        let input = key.decrypt(&read(&encrypted)?).map_err(|error| format!("{}: {error}", encrypted.display()))?;
+        write(&plain, input.as_bytes())?;
+        remove(&encrypted)?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Re-encrypts every `input.enc` from `old` to `new`. Everything is decrypted
+/// before the first file is written, so a wrong old key changes nothing.
+pub fn rotate_all(resources: &[&Path], old: &InputKey, new: &InputKey) -> Result<usize, String> {
+    let mut inputs: Vec<(PathBuf, String)> = Vec::new();
+
+    for (_, encrypted) in resources.iter().map(|resources| input_paths(resources)) {
+        if !encrypted.exists() {
+            continue;
+        }
+
+        let input = old.decrypt(&read(&encrypted)?).map_err(|error| format!("{}: {error}", encrypted.display()))?;
+        inputs.push((encrypted, input));
+    }
+
+    for (encrypted, input) in &inputs {
+        write(encrypted, &new.encrypt(input))?;
+    }
+
+    Ok(inputs.len())
+}
+
+#[test]
+fn encrypt_rotate_decrypt() {
+    let root = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+    let (day_1, day_2) = (root.join("day01/res"), root.join("day02/res"));
+    std::fs::create_dir_all(&day_1).unwrap();
+    std::fs::create_dir_all(&day_2).unwrap();
+    std::fs::write(day_1.join("input"), "1\n").unwrap();
+    std::fs::write(day_2.join("input"), "2\n").unwrap();
+
+    let resources = [day_1.as_path(), day_2.as_path()];
+    let (old, new) = (InputKey::generate(), InputKey::generate());
+
+    assert_eq!(encrypt_all(&resources, &old), Ok(2));
+    assert!(!day_1.join("input").exists());
+    assert!(day_1.join("input.enc").exists());
+
+    assert!(rotate_all(&resources, &new, &old).is_err());
+    assert_eq!(rotate_all(&resources, &old, &new), Ok(2));
+    assert!(decrypt_all(&resources, &old).is_err());
+
+    assert_eq!(decrypt_all(&resources, &new), Ok(2));
+    assert_eq!(std::fs::read_to_string(day_2.join("input")).unwrap(), "2\n");
+    assert!(!day_2.join("input.enc").exists());
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
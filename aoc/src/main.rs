@@ -0,0 +1,341 @@
+mod answers;
+mod bench;
+mod inputs;
+mod registry;
+mod remote;
+mod scaffold;
+mod verify;
+
+use std::path::Path;
+use std::process::ExitCode;
+use common::{InputKey, Part, RunArgs, INPUT_USAGE};
+use answers::{Answers, Key, Verdict};
+use bench::Baseline;
+
+const USAGE: &str = "\
+usage: aoc run <year> <day> [--part <1|2>] [input selection]
+       aoc verify [<year> [<day>]] [--record]
+       aoc bench [<year> [<day>]] [--iterations <n>] [--threshold <percent>] [--save-baseline]
+       aoc fetch <year> [<day>] [--refresh]
+       aoc submit <year> <day> <part> [<answer>]
+       aoc inputs <encrypt|decrypt|rotate>
+       aoc new <year> <day>
+       aoc list";
+
+fn run(args: &[String]) -> Result<(), String> {
+    let run_args = RunArgs::parse(args)?;
+
+    let [year, day] = run_args.positional.as_slice() else {
+        return Err(format!("expected <year> <day>, got {} argument(s)", run_args.positional.len()));
+    };
+
+    let year = year.parse::<u16>().map_err(|_| format!("invalid year '{year}'"))?;
+    let day = day.parse::<u8>().map_err(|_| format!("invalid day '{day}'"))?;
+
+    let entry = registry::find(year, day).ok_or(format!("no solution for {year} day {day}"))?;
+
+    let input = run_args.source
+        .read(Path::new(entry.resources))
+        .map_err(|error| error.to_string())?;
+
+    common::run(entry.puzzle, &run_args.source.name(), &input, run_args.part)
+}
+
+/// Parses the optional `[<year> [<day>]]` filter of `verify` and `bench`.
+fn parse_year_day(positional: &[&String]) -> Result<(Option<u16>, Option<u8>), String> {
+    let year = positional
+        .first()
+        .map(|year| year.parse::<u16>().map_err(|_| format!("invalid year '{year}'")))
+        .transpose()?;
+    let day = positional
+        .get(1)
+        .map(|day| day.parse::<u8>().map_err(|_| format!("invalid day '{day}'")))
+        .transpose()?;
+
+    if positional.len() > 2 {
+        return Err(format!("unexpected argument '{}'", positional[2]));
+    }
+
+    Ok((year, day))
+}
+
+fn verify(args: &[String]) -> Result<(), String> {
+    let mut record = false;
+    let mut positional: Vec<&String> = Vec::new();
+
+    for arg in args {
+        match arg.as_str() {
+            "--record" => record = true,
+            option if option.starts_with('-') => return Err(format!("unknown option '{option}'")),
+            _ => positional.push(arg),
+        }
+    }
+
+    let (year, day) = parse_year_day(&positional)?;
+
+    let mut answers = Answers::load(&Answers::default_path())?;
+
+    match verify::verify(&mut answers, year, day, record)? {
+        true => Ok(()),
+        false => Err("some answers changed".to_string()),
+    }
+}
+
+fn bench(args: &[String]) -> Result<(), String> {
+    let mut options = bench::Options { iterations: 10, threshold: 0.2, save_baseline: false };
+    let mut positional: Vec<&String> = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--iterations" | "-n" => {
+                let value = iter.next().ok_or("--iterations expects a value")?;
+                options.iterations = value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or(format!("invalid number of iterations '{value}'"))?;
+            },
+            "--threshold" => {
+                let value = iter.next().ok_or("--threshold expects a value")?;
+                let percent = value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|p| *p >= 0.0)
+                    .ok_or(format!("invalid threshold '{value}'"))?;
+                options.threshold = percent / 100.0;
+            },
+            "--save-baseline" => options.save_baseline = true,
+            option if option.starts_with('-') => return Err(format!("unknown option '{option}'")),
+            _ => positional.push(arg),
+        }
+    }
+
+    let (year, day) = parse_year_day(&positional)?;
+
+    let mut baseline = Baseline::load(&Baseline::default_path())?;
+
+    match bench::bench(&mut baseline, year, day, &options)? {
+        true => Ok(()),
+        false => Err("some phases regressed or failed".to_string()),
+    }
+}
+
+/// Stores a downloaded input as the day's `res/input`, unless that already holds a different input.
+fn install_input(entry: &registry::Entry, input: &str) -> Result<&'static str, String> {
+    let path = Path::new(entry.resources).join("input");
+
+    match common::read_resource(Path::new(entry.resources), "input") {
+        Ok(existing) if existing == input => Ok("up to date"),
+        Ok(existing) if !existing.is_empty() => {
+            Err(format!("{} differs from the downloaded input, remove it to replace it", path.display()))
+        },
+        _ => std::fs::write(&path, input)
+            .map(|()| "installed")
+            .map_err(|error| format!("{}: {error}", path.display())),
+    }
+}
+
+fn fetch(args: &[String]) -> Result<(), String> {
+    let mut refresh = false;
+    let mut positional: Vec<&String> = Vec::new();
+
+    for arg in args {
+        match arg.as_str() {
+            "--refresh" => refresh = true,
+            option if option.starts_with('-') => return Err(format!("unknown option '{option}'")),
+            _ => positional.push(arg),
+        }
+    }
+
+    let (Some(year), day) = parse_year_day(&positional)? else {
+        return Err("expected <year> [<day>]".to_string());
+    };
+
+    let days: Vec<u8> = match day {
+        Some(day) => [day].into(),
+        None => registry::REGISTRY
+            .iter()
+            .filter(|entry| entry.puzzle.year() == year)
+            .map(|entry| entry.puzzle.day())
+            .collect(),
+    };
+
+    let client = remote::Client::new(remote::Config::from_env()?);
+
+    for day in days {
+        let input = client.input(year, day, refresh)?;
+
+        match registry::find(year, day) {
+            Some(entry) => println!("{year} day {day:>2}: {}", install_input(entry, &input)?),
+            None => println!("{year} day {day:>2}: cached in {}", client.cached_input_path(year, day).display()),
+        }
+    }
+
+    Ok(())
+}
+
+/// Submits an answer for the real input, by default the one the solution computes.
+fn submit(args: &[String]) -> Result<(), String> {
+    let (year, day, part, answer) = match args {
+        [year, day, part] => (year, day, part, None),
+        [year, day, part, answer] => (year, day, part, Some(answer.clone())),
+        _ => return Err(format!("expected <year> <day> <part> [<answer>], got {} argument(s)", args.len())),
+    };
+
+    let year = year.parse::<u16>().map_err(|_| format!("invalid year '{year}'"))?;
+    let day = day.parse::<u8>().map_err(|_| format!("invalid day '{day}'"))?;
+    let part = part
+        .parse::<u8>()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or(format!("invalid part '{part}', expected 1 or 2"))?;
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let entry = registry::find(year, day).ok_or(format!("no solution for {year} day {day}"))?;
+            let input = common::InputSource::Input
+                .read(Path::new(entry.resources))
+                .map_err(|error| error.to_string())?;
+            entry.puzzle
+                .solve(&input, part)
+                .map_err(|error| format!("{year} day {day}: {}", error.with_input_name("input")))?
+                .to_string()
+        },
+    };
+
+    let mut answers = Answers::load(&Answers::default_path())?;
+    let key = Key { year, day, part, input: "input".to_string() };
+
+    answers
+        .check_submission(&key, &answer)
+        .map_err(|reason| format!("not submitting {answer}: {reason}"))?;
+
+    let client = remote::Client::new(remote::Config::from_env()?);
+
+    let verdict = match client.submit(year, day, part, &answer)? {
+        remote::Outcome::Correct => {
+            println!("{year} day {day} part {part}: {answer} is correct");
+            answers.insert(key, answer);
+            return answers.save();
+        },
+        remote::Outcome::AlreadySolved => {
+            return Err(format!("{year} day {day} part {part} is already solved or not unlocked, the answer was not checked"));
+        },
+        remote::Outcome::RateLimited(wait) => {
+            return Err(format!("submitted too recently, wait {}s before trying again", wait.as_secs()));
+        },
+        remote::Outcome::TooHigh => Verdict::TooHigh,
+        remote::Outcome::TooLow => Verdict::TooLow,
+        remote::Outcome::Wrong => Verdict::Wrong,
+    };
+
+    answers.reject(key, answer.clone(), verdict);
+    answers.save()?;
+
+    Err(format!("{year} day {day} part {part}: {answer} is wrong ({})", verdict.as_str()))
+}
+
+fn write_key(path: &Path, key: &InputKey) -> Result<(), String> {
+    std::fs::write(path, key.to_hex() + "\n").map_err(|error| format!("{}: {error}", path.display()))
+}
+
+/// Encrypts, decrypts or re-keys the `res/input` of every registered day.
+fn inputs(args: &[String]) -> Result<(), String> {
+    let resources: Vec<&Path> = registry::REGISTRY.iter().map(|entry| Path::new(entry.resources)).collect();
+    let key_file = InputKey::key_file();
+    let no_key = || format!("no key, set AOC_INPUT_KEY or create {}", key_file.display());
+
+    match args {
+        [command] if command == "encrypt" => {
+            let key = match InputKey::load()? {
+                Some(key) => key,
+                None => {
+                    let key = InputKey::generate();
+                    write_key(&key_file, &key)?;
+                    println!("generated a new key in {}, keep a copy of it somewhere safe", key_file.display());
+                    key
+                },
+            };
+
+            println!("encrypted {} inputs", inputs::encrypt_all(&resources, &key)?);
+        },
+        [command] if command == "decrypt" => {
+            let key = InputKey::load()?.ok_or_else(no_key)?;
+            println!("decrypted {} inputs", inputs::decrypt_all(&resources, &key)?);
+        },
+        [command] if command == "rotate" => {
+            let old = InputKey::load()?.ok_or_else(no_key)?;
+            let new = InputKey::generate();
+
+            // The new key is stored before any input is re-encrypted with it.
+            let new_key_file = key_file.with_extension("new");
+            write_key(&new_key_file, &new)?;
+
+            println!("re-encrypted {} inputs", inputs::rotate_all(&resources, &old, &new)?);
+
+            // The temporary file holds a live key, so it must not outlive the rotation.
+            std::fs::rename(&new_key_file, &key_file).map_err(|error| format!("{}: {error}", key_file.display()))?;
+            println!("the new key is in {}", key_file.display());
+
+            if std::env::var_os("AOC_INPUT_KEY").is_some() {
+                println!("AOC_INPUT_KEY still holds the old key, set it to the new one");
+            }
+        },
+        _ => return Err("expected one of encrypt, decrypt or rotate".to_string()),
+    }
+
+    Ok(())
+}
+
+fn new(args: &[String]) -> Result<(), String> {
+    let [year, day] = args else {
+        return Err(format!("expected <year> <day>, got {} argument(s)", args.len()));
+    };
+
+    let year = year.parse::<u16>().map_err(|_| format!("invalid year '{year}'"))?;
+    let day = day.parse::<u8>().map_err(|_| format!("invalid day '{day}'"))?;
+
+    let root = Path::new(scaffold::WORKSPACE_ROOT);
+    let directory = scaffold::new_day(root, year, day)?;
+
+    println!("created {}", directory.strip_prefix(root).unwrap_or(&directory).display());
+    println!("add the example to res/example, its answers to res/example.answers and the puzzle input to res/input");
+
+    Ok(())
+}
+
+fn list() {
+    for entry in registry::REGISTRY {
+        println!("{} day {}", entry.puzzle.year(), entry.puzzle.day());
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let outcome = match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("fetch") => fetch(&args[1..]),
+        Some("submit") => submit(&args[1..]),
+        Some("inputs") => inputs(&args[1..]),
+        Some("new") => new(&args[1..]),
+        Some("list") => {
+            list();
+            Ok(())
+        },
+        Some(command) => Err(format!("unknown command '{command}'\n{USAGE}\n{INPUT_USAGE}")),
+        None => Err(format!("{USAGE}\n{INPUT_USAGE}")),
+    };
+
+    match outcome {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        },
+    }
+}
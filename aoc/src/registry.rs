@@ -0,0 +1,62 @@
+use common::Puzzle;
+
+pub struct Entry {
+    pub puzzle: &'static dyn Puzzle,
+    pub resources: &'static str,
+}
+
+/// Every solved day, ordered by year and day.
+pub static REGISTRY: &[Entry] = &[
+    Entry { puzzle: &aoc_2023_day_01::Day01, resources: aoc_2023_day_01::RESOURCES },
+    Entry { puzzle: &aoc_2023_day_02::Day02, resources: aoc_2023_day_02::RESOURCES },
+    Entry { puzzle: &aoc_2023_day_03::Day03, resources: aoc_2023_day_03::RESOURCES },
+    Entry { puzzle: &aoc_2023_day_04::Day04, resources: aoc_2023_day_04::RESOURCES },
+    Entry { puzzle: &aoc_2023_day_05::Day05, resources: aoc_2023_day_05::RESOURCES },
+    Entry { puzzle: &aoc_2023_day_06::Day06, resources: aoc_2023_day_06::RESOURCES },
+    Entry { puzzle: &aoc_2023_day_07::Day07, resources: aoc_2023_day_07::RESOURCES },
+    Entry { puzzle: &aoc_2023_day_08::Day08, resources: aoc_2023_day_08::RESOURCES },
+    Entry { puzzle: &aoc_2023_day_09::Day09, resources: aoc_2023_day_09::RESOURCES },
+    Entry { puzzle: &aoc_2023_day_10::Day10, resources: aoc_2023_day_10::RESOURCES },
+    Entry { puzzle: &aoc_2023_day_11::Day11, resources: aoc_2023_day_11::RESOURCES },
+    Entry { puzzle: &aoc_2024_day01::Day01, resources: aoc_2024_day01::RESOURCES },
+    Entry { puzzle: &aoc_2024_day02::Day02, resources: aoc_2024_day02::RESOURCES },
+    Entry { puzzle: &aoc_2024_day03::Day03, resources: aoc_2024_day03::RESOURCES },
+    Entry { puzzle: &aoc_2024_day04::Day04, resources: aoc_2024_day04::RESOURCES },
+    Entry { puzzle: &aoc_2024_day05::Day05, resources: aoc_2024_day05::RESOURCES },
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Entry> {
+    REGISTRY
+        .iter()
+        .find(|entry| entry.puzzle.year() == year && entry.puzzle.day() == day)
+}
+
+#[test]
+fn windows_edited_examples_are_solved() {
+    use common::{read_resource, Part};
+    use crate::answers::{Answers, Key};
+
+    let answers = Answers::load(&Answers::default_path()).unwrap();
+
+    for entry in REGISTRY {
+        let (year, day) = (entry.puzzle.year(), entry.puzzle.day());
+        let mut n_checked = 0;
+
+        for name in answers.inputs_of(year, day).into_iter().filter(|name| name.starts_with("example")) {
+            let input = read_resource(std::path::Path::new(entry.resources), &name).unwrap();
+            let edited = format!("\u{feff}{}\r\n\r\n", input.replace('\n', "\r\n"));
+
+            for part in [Part::One, Part::Two] {
+                let Some(expected) = answers.get(&Key { year, day, part, input: name.clone() }) else {
+                    continue;
+                };
+
+                let answer = entry.puzzle.solve(&edited, part).map(|answer| answer.to_string());
+                assert_eq!(answer.as_deref(), Ok(expected), "{year} day {day} part {part} of {name}");
+                n_checked += 1;
+            }
+        }
+
+        assert!(n_checked > 0, "{year} day {day} has no example answers");
+    }
+}
@@ -0,0 +1,379 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use common::Part;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_CACHE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../.cache");
+pub const DEFAULT_SESSION_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../.session");
+const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+const USER_AGENT: &str = concat!("aoc/", env!("CARGO_PKG_VERSION"), " (personal puzzle runner)");
+
+/// Where and how to talk to the puzzle server.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub cache_dir: PathBuf,
+    /// Minimal time between two requests, also across runs.
+    pub min_interval: Duration,
+}
+
+impl Config {
+    /// Reads `AOC_BASE_URL`, `AOC_SESSION` (or the git-ignored `.session` file),
+    /// `AOC_CACHE_DIR` and `AOC_MIN_INTERVAL` (in seconds), falling back to defaults.
+    pub fn from_env() -> Result<Self, String> {
+        let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+        let session = match var("AOC_SESSION") {
+            Some(session) => Some(session),
+            None => std::fs::read_to_string(DEFAULT_SESSION_FILE)
+                .ok()
+                .map(|session| session.trim().to_string())
+                .filter(|session| !session.is_empty()),
+        };
+
+        let min_interval = match var("AOC_MIN_INTERVAL") {
+            Some(seconds) => seconds
+                .parse::<f64>()
+                .ok()
+                .filter(|seconds| *seconds >= 0.0)
+                .map(Duration::from_secs_f64)
+                .ok_or(format!("invalid AOC_MIN_INTERVAL '{seconds}'"))?,
+            None => DEFAULT_MIN_INTERVAL,
+        };
+
+        Ok(Config {
+            base_url: var("AOC_BASE_URL").unwrap_or(DEFAULT_BASE_URL.to_string()),
+            session,
+            cache_dir: var("AOC_CACHE_DIR").map(PathBuf::from).unwrap_or(PathBuf::from(DEFAULT_CACHE_DIR)),
+            min_interval,
+        })
+    }
+}
+
+fn unix_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+/// What the server said to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about the direction.
+    Wrong,
+    RateLimited(Duration),
+    /// The part was solved before, so the answer was not checked.
+    AlreadySolved,
+}
+
+/// Parses a wait like "You have 1m 5s left to wait" out of the response page.
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+
+    page[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let unit_start = amount.find(|c: char| !c.is_ascii_digit())?;
+            let value = amount[..unit_start].parse::<u64>().ok()?;
+            match &amount[unit_start..] {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Classifies the page the server answers a submission with.
+pub fn parse_response(page: &str) -> Result<Outcome, String> {
+    if page.contains("That's the right answer") {
+        Ok(Outcome::Correct)
+    }
+    else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Ok(Outcome::TooHigh)
+        }
+        else if page.contains("your answer is too low") {
+            Ok(Outcome::TooLow)
+        }
+        else {
+            Ok(Outcome::Wrong)
+        }
+    }
+    else if page.contains("You gave an answer too recently") {
+        Ok(Outcome::RateLimited(parse_wait(page).unwrap_or(Duration::from_secs(60))))
+    }
+    else if page.contains("You don't seem to be solving the right level") {
+        Ok(Outcome::AlreadySolved)
+    }
+    else {
+        Err("unrecognised response to the submission".to_string())
+    }
+}
+
+/// Client of the puzzle server; authenticated with the session cookie of the
+/// website and rate limited, since everything it fetches is cached anyway.
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Client { config, agent }
+    }
+
+    fn session(self: &Self) -> Result<&str, String> {
+        self.config
+            .session
+            .as_deref()
+            .ok_or(format!("no session token, set AOC_SESSION or write it to {DEFAULT_SESSION_FILE}"))
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request, which is
+    /// remembered in the cache directory so it also holds across runs.
+    fn wait_for_turn(self: &Self) -> Result<(), String> {
+        let stamp = self.config.cache_dir.join(".last-request");
+
+        if let Some(last) = std::fs::read_to_string(&stamp).ok().and_then(|millis| millis.trim().parse::<u64>().ok()) {
+            let elapsed = Duration::from_millis(unix_millis(SystemTime::now()).saturating_sub(last));
+            if elapsed < self.config.min_interval {
+                std::thread::sleep(self.config.min_interval - elapsed);
+            }
+        }
+
+        std::fs::create_dir_all(&self.config.cache_dir)
+            .and_then(|()| std::fs::write(&stamp, unix_millis(SystemTime::now()).to_string()))
+            .map_err(|error| format!("{}: {error}", stamp.display()))
+    }
+
+    fn url(self: &Self, path: &str) -> String {
+        format!("{}{path}", self.config.base_url.trim_end_matches('/'))
+    }
+
+    /// Turns a failed request into a message; the server answers 400 or 500
+    /// to an expired session and 404 to puzzles that are not unlocked yet.
+    fn describe_error(url: &str, error: ureq::Error) -> String {
+        match error {
+            ureq::Error::Status(404, _) => format!("{url}: not found, the puzzle may not be unlocked yet"),
+            ureq::Error::Status(code @ (400 | 500), _) => format!("{url}: status {code}, the session token may be invalid or expired"),
+            ureq::Error::Status(code, _) => format!("{url}: unexpected status {code}"),
+            ureq::Error::Transport(transport) => format!("{url}: {transport}"),
+        }
+    }
+
+    fn get(self: &Self, path: &str) -> Result<String, String> {
+        let session = self.session()?;
+        self.wait_for_turn()?;
+
+        let url = self.url(path);
+        self.agent
+            .get(&url)
+            .set("Cookie", &format!("session={session}"))
+            .call()
+            .map_err(|error| Self::describe_error(&url, error))?
+            .into_string()
+            .map_err(|error| format!("{url}: {error}"))
+    }
+
+    fn post(self: &Self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let session = self.session()?;
+        self.wait_for_turn()?;
+
+        let url = self.url(path);
+        self.agent
+            .post(&url)
+            .set("Cookie", &format!("session={session}"))
+            .send_form(form)
+            .map_err(|error| Self::describe_error(&url, error))?
+            .into_string()
+            .map_err(|error| format!("{url}: {error}"))
+    }
+
+    /// Submits `answer` for a part; the caller records the outcome.
+    pub fn submit(self: &Self, year: u16, day: u8, part: Part, answer: &str) -> Result<Outcome, String> {
+        let level = part.number().to_string();
+        let page = self.post(&format!("/{year}/day/{day}/answer"), &[("level", &level), ("answer", answer)])?;
+
+        parse_response(&page)
+    }
+
+    pub fn cached_input_path(self: &Self, year: u16, day: u8) -> PathBuf {
+        self.config.cache_dir.join(year.to_string()).join(day.to_string()).join("input")
+    }
+
+    /// The puzzle input of a day, downloaded only if it is not cached yet or `refresh` is set.
+    pub fn input(self: &Self, year: u16, day: u8, refresh: bool) -> Result<String, String> {
+        let path = self.cached_input_path(year, day);
+
+        if !refresh {
+            if let Ok(input) = std::fs::read_to_string(&path) {
+                return Ok(input);
+            }
+        }
+
+        let input = self.get(&format!("/{year}/day/{day}/input"))?;
+
+        write_file(&path, &input)?;
+
+        Ok(input)
+    }
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|error| format!("{}: {error}", parent.display()))?;
+    }
+    std::fs::write(path, content).map_err(|error| format!("{}: {error}", path.display()))
+}
+
+/// Serves the canned `(status, body)` responses, one per connection, on a
+/// local port; the thread returns the raw requests it received.
+#[cfg(test)]
+pub fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, std::thread::JoinHandle<Vec<String>>) {
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = std::thread::spawn(move || {
+        let mut requests = Vec::new();
+
+        for (status, body) in responses {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut request = String::new();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                    break;
+                }
+                request += &line;
+            }
+
+            let content_length = request
+                .lines()
+                .find_map(|line| line.to_ascii_lowercase().strip_prefix("content-length:").map(|n| n.trim().parse::<usize>().unwrap()))
+                .unwrap_or(0);
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request += "\r\n";
+            request += &String::from_utf8(content).unwrap();
+
+            let response = format!(
+                "HTTP/1.1 {status} Canned\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+
+            requests.push(request);
+        }
+
+        requests
+    });
+
+    (base_url, handle)
+}
+
+#[cfg(test)]
+pub fn test_config(name: &str, base_url: &str) -> Config {
+    let cache_dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&cache_dir);
+
+    Config {
+        base_url: base_url.to_string(),
+        session: Some("53cr3t".to_string()),
+        cache_dir,
+        min_interval: Duration::ZERO,
+    }
+}
+
+#[test]
+fn input_is_downloaded_once() {
+    let (base_url, server) = mock_server([(200, "1 2 3\n")].into());
+    let client = Client::new(test_config("input-cache", &base_url));
+
+    assert_eq!(client.input(2023, 9, false), Ok("1 2 3\n".to_string()));
+    assert_eq!(client.input(2023, 9, false), Ok("1 2 3\n".to_string()));
+
+    let requests = server.join().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /2023/day/9/input HTTP/1.1\r\n"));
+    assert!(requests[0].to_ascii_lowercase().contains("cookie: session=53cr3t\r\n"));
+    assert!(client.cached_input_path(2023, 9).exists());
+
+    std::fs::remove_dir_all(&client.config.cache_dir).unwrap();
+}
+
+#[test]
+fn input_errors_are_described() {
+    let (base_url, server) = mock_server([(404, "Not Found"), (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.")].into());
+    let client = Client::new(test_config("input-errors", &base_url));
+
+    assert!(client.input(2030, 1, false).unwrap_err().contains("not be unlocked yet"));
+    assert!(client.input(2023, 1, false).unwrap_err().contains("session token"));
+    assert!(!client.cached_input_path(2023, 1).exists());
+    server.join().unwrap();
+
+    let client = Client::new(Config { session: None, ..test_config("input-no-session", &base_url) });
+    assert!(client.input(2023, 1, false).unwrap_err().contains("no session token"));
+}
+
+#[test]
+fn requests_are_rate_limited() {
+    let (base_url, server) = mock_server([(200, "a"), (200, "b")].into());
+    let config = Config { min_interval: Duration::from_millis(300), ..test_config("rate-limit", &base_url) };
+    let client = Client::new(config);
+
+    let start = std::time::Instant::now();
+    assert_eq!(client.input(2023, 1, true), Ok("a".to_string()));
+    assert_eq!(client.input(2023, 1, true), Ok("b".to_string()));
+    assert!(start.elapsed() >= Duration::from_millis(300));
+
+    server.join().unwrap();
+    std::fs::remove_dir_all(&client.config.cache_dir).unwrap();
+}
+
+#[test]
+fn responses_are_classified() {
+    let page = |text: &str| format!("<html><body><main><article><p>{text}</p></article></main></body></html>");
+
+    assert_eq!(parse_response(&page("That's the right answer!  You are one gold star closer.")), Ok(Outcome::Correct));
+    assert_eq!(parse_response(&page("That's not the right answer; your answer is too high.")), Ok(Outcome::TooHigh));
+    assert_eq!(parse_response(&page("That's not the right answer; your answer is too low.")), Ok(Outcome::TooLow));
+    assert_eq!(parse_response(&page("That's not the right answer.  If you're stuck, ...")), Ok(Outcome::Wrong));
+    assert_eq!(
+        parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.")),
+        Ok(Outcome::RateLimited(Duration::from_secs(65)))
+    );
+    assert_eq!(
+        parse_response(&page("You don't seem to be solving the right level.  Did you already complete it?")),
+        Ok(Outcome::AlreadySolved)
+    );
+    assert!(parse_response(&page("Something else")).is_err());
+}
+
+#[test]
+fn answer_is_posted() {
+    let (base_url, server) = mock_server([(200, "<p>That's not the right answer; your answer is too low.</p>")].into());
+    let client = Client::new(test_config("submit", &base_url));
+
+    assert_eq!(client.submit(2023, 8, Part::Two, "1234"), Ok(Outcome::TooLow));
+
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with("POST /2023/day/8/answer HTTP/1.1\r\n"));
+    assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=1234"));
+
+    std::fs::remove_dir_all(&client.config.cache_dir).unwrap();
+}
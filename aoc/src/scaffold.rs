@@ -0,0 +1,176 @@
+use std::path::{Path, PathBuf};
+
+pub const WORKSPACE_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+const CARGO_TOML_TEMPLATE: &str = include_str!("../templates/Cargo.toml.template");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.template");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.template");
+const EXAMPLE_ANSWERS_TEMPLATE: &str = include_str!("../templates/example.answers.template");
+
+/// Names of a day crate; 2023 uses `day-01` directories, later years `day01`.
+#[derive(Debug, PartialEq, Eq)]
+struct DayNames {
+    directory: String,
+    package: String,
+    crate_name: String,
+    type_name: String,
+}
+
+impl DayNames {
+    fn new(root: &Path, year: u16, day: u8) -> Self {
+        let dashed = std::fs::read_dir(root.join(year.to_string()))
+            .map(|entries| entries
+                .filter_map(|entry| entry.ok())
+                .any(|entry| entry.file_name().to_string_lossy().starts_with("day-")))
+            .unwrap_or(false);
+
+        let day_name = if dashed { format!("day-{day:02}") } else { format!("day{day:02}") };
+        let package = format!("aoc-{year}-{day_name}");
+
+        DayNames {
+            directory: format!("{year}/{day_name}"),
+            crate_name: package.replace('-', "_"),
+            package,
+            type_name: format!("Day{day:02}"),
+        }
+    }
+
+    fn fill(self: &Self, template: &str, year: u16, day: u8) -> String {
+        template
+            .replace("{{package}}", &self.package)
+            .replace("{{crate}}", &self.crate_name)
+            .replace("{{type}}", &self.type_name)
+            .replace("{{year}}", &year.to_string())
+            .replace("{{day}}", &day.to_string())
+    }
+}
+
+/// Inserts `line` into the block of lines starting with `prefix`, keeping that
+/// block sorted; the block has to exist already.
+fn insert_sorted(content: &str, prefix: &str, line: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+
+    let block: Vec<usize> = (0..lines.len()).filter(|i| lines[*i].starts_with(prefix)).collect();
+    let (Some(first), Some(last)) = (block.first(), block.last()) else {
+        return Err(format!("no line starting with '{prefix}'"));
+    };
+
+    if lines[*first..=*last].contains(&line) {
+        return Err(format!("'{}' is already there", line.trim()));
+    }
+
+    let position = block
+        .iter()
+        .find(|i| lines[**i] > line)
+        .copied()
+        .unwrap_or(last + 1);
+    lines.insert(position, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn write_new_file(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|error| format!("{}: {error}", parent.display()))?;
+    }
+    std::fs::write(path, content).map_err(|error| format!("{}: {error}", path.display()))
+}
+
+/// Creates the crate of a new day from the templates and registers it in the
+/// workspace, the `aoc` dependencies and the registry.
+///
+/// Returns the directory of the new crate.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<PathBuf, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day '{day}', expected 1 to 25"));
+    }
+
+    let names = DayNames::new(root, year, day);
+    let directory = root.join(&names.directory);
+
+    if directory.exists() {
+        return Err(format!("{} already exists", directory.display()));
+    }
+
+    // Prepare all edits of existing files first, so a failure changes nothing.
+    let workspace_member = format!("    \"{}\",", names.directory);
+    let dependency = format!("{} = {{ path = \"../{}\" }}", names.package, names.directory);
+    let registry_entry = format!(
+        "    Entry {{ puzzle: &{krate}::{day_type}, resources: {krate}::RESOURCES }},",
+        krate = names.crate_name,
+        day_type = names.type_name,
+    );
+
+    // Day crates are the workspace members starting with their year.
+    let edits = [
+        ("Cargo.toml", "    \"2", workspace_member),
+        ("aoc/Cargo.toml", "aoc-", dependency),
+        ("aoc/src/registry.rs", "    Entry {", registry_entry),
+    ];
+
+    let mut edited: Vec<(PathBuf, String)> = Vec::new();
+    for (file, prefix, line) in edits {
+        let path = root.join(file);
+        let content = std::fs::read_to_string(&path)
+            .map_err(|error| error.to_string())
+            .and_then(|content| insert_sorted(&content, prefix, &line))
+            .map_err(|error| format!("{}: {error}", path.display()))?;
+        edited.push((path, content));
+    }
+
+    for (path, content) in edited {
+        std::fs::write(&path, content).map_err(|error| format!("{}: {error}", path.display()))?;
+    }
+
+    write_new_file(&directory.join("Cargo.toml"), &names.fill(CARGO_TOML_TEMPLATE, year, day))?;
+    write_new_file(&directory.join("src/main.rs"), &names.fill(MAIN_TEMPLATE, year, day))?;
+    write_new_file(&directory.join("src/lib.rs"), &names.fill(LIB_TEMPLATE, year, day))?;
+    write_new_file(&directory.join("res/example"), "")?;
+    write_new_file(&directory.join("res/example.answers"), EXAMPLE_ANSWERS_TEMPLATE)?;
+    write_new_file(&directory.join("res/input"), "")?;
+
+    Ok(directory)
+}
+
+#[test]
+fn insert_sorted_keeps_block_order() {
+    let content = "[workspace]\nmembers = [\n    \"aoc\",\n    \"2023/day-01\",\n    \"2024/day05\",\n]\n";
+
+    let result = insert_sorted(content, "    \"2", "    \"2024/day03\",").unwrap();
+    assert_eq!(result, "[workspace]\nmembers = [\n    \"aoc\",\n    \"2023/day-01\",\n    \"2024/day03\",\n    \"2024/day05\",\n]\n");
+
+    let result = insert_sorted(content, "    \"2", "    \"2024/day06\",").unwrap();
+    assert!(result.ends_with("    \"2024/day05\",\n    \"2024/day06\",\n]\n"));
+
+    assert!(insert_sorted(content, "    \"2", "    \"2024/day05\",").is_err());
+}
+
+#[test]
+fn new_day_creates_and_registers_crate() {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+
+    write_new_file(&root.join("Cargo.toml"), "[workspace]\nmembers = [\n    \"aoc\",\n    \"2024/day01\",\n]\n").unwrap();
+    write_new_file(&root.join("aoc/Cargo.toml"), "[dependencies]\naoc-2024-day01 = { path = \"../2024/day01\" }\n").unwrap();
+    write_new_file(
+        &root.join("aoc/src/registry.rs"),
+        "pub static REGISTRY: &[Entry] = &[\n    Entry { puzzle: &aoc_2024_day01::Day01, resources: aoc_2024_day01::RESOURCES },\n];\n",
+    ).unwrap();
+    std::fs::create_dir_all(root.join("2024/day01")).unwrap();
+
+    let directory = new_day(&root, 2024, 6).unwrap();
+    assert_eq!(directory, root.join("2024/day06"));
+
+    let read = |path: &str| std::fs::read_to_string(root.join(path)).unwrap();
+    assert!(read("Cargo.toml").contains("    \"2024/day01\",\n    \"2024/day06\",\n"));
+    assert!(read("aoc/Cargo.toml").contains("aoc-2024-day06 = { path = \"../2024/day06\" }\n"));
+    assert!(read("aoc/src/registry.rs").contains("    Entry { puzzle: &aoc_2024_day06::Day06, resources: aoc_2024_day06::RESOURCES },\n"));
+    assert!(read("2024/day06/Cargo.toml").contains("name = \"aoc-2024-day06\""));
+    assert!(read("2024/day06/src/main.rs").contains("use aoc_2024_day06::{Day06, RESOURCES};"));
+    assert!(read("2024/day06/src/lib.rs").contains("const DAY: u8 = 6;"));
+    assert_eq!(read("2024/day06/res/input"), "");
+
+    assert!(new_day(&root, 2024, 6).is_err());
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,104 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+use common::{Error, Part};
+use crate::answers::{Answers, Key};
+use crate::registry::{self, Entry};
+
+#[derive(Debug, Default)]
+struct Summary {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+    recorded: usize,
+}
+
+fn verify_entry(entry: &Entry, answers: &mut Answers, record: bool, summary: &mut Summary) {
+    let (year, day) = (entry.puzzle.year(), entry.puzzle.day());
+
+    let recorded_inputs: BTreeSet<String> = answers.inputs_of(year, day);
+
+    let mut inputs = recorded_inputs.clone();
+    inputs.insert("input".to_string());
+
+    for input_name in inputs {
+        let input = match common::read_resource(Path::new(entry.resources), &input_name) {
+            Ok(input) => input,
+            Err(_) if !recorded_inputs.contains(&input_name) => continue,
+            Err(error) => {
+                println!("FAIL     {year} day {day:>2} ({input_name}): {error}");
+                summary.failed += 1;
+                continue;
+            },
+        };
+
+        for part in Part::ALL {
+            let key = Key { year, day, part, input: input_name.clone() };
+            let expected = answers.get(&key).map(|answer| answer.to_string());
+
+            // Examples often only apply to one part, so only the real input counts as missing.
+            if expected.is_none() && input_name != "input" {
+                continue;
+            }
+
+            let label = format!("{year} day {day:>2} part {part} ({input_name})");
+
+            let actual = match entry.puzzle.solve(&input, part) {
+                Ok(answer) => answer.to_string(),
+                Err(Error::Unsolved(_)) if expected.is_none() => continue,
+                Err(error) => {
+                    println!("FAIL     {label}: {}", error.with_input_name(&input_name));
+                    summary.failed += 1;
+                    continue;
+                },
+            };
+
+            match expected {
+                Some(expected) if expected == actual => {
+                    println!("PASS     {label}");
+                    summary.passed += 1;
+                },
+                Some(expected) => {
+                    println!("FAIL     {label}: expected {expected}, got {actual}");
+                    summary.failed += 1;
+                },
+                None if record => {
+                    println!("RECORD   {label}: {actual}");
+                    answers.insert(key, actual);
+                    summary.recorded += 1;
+                },
+                None => {
+                    println!("MISSING  {label}: got {actual}");
+                    summary.missing += 1;
+                },
+            }
+        }
+    }
+}
+
+/// Runs every registered solution (optionally only of one year or day) against
+/// every input with recorded answers and compares the results.
+///
+/// Returns whether no answer changed.
+pub fn verify(answers: &mut Answers, year: Option<u16>, day: Option<u8>, record: bool) -> Result<bool, String> {
+    let mut summary = Summary::default();
+
+    let entries = registry::REGISTRY
+        .iter()
+        .filter(|entry| year.is_none_or(|year| entry.puzzle.year() == year))
+        .filter(|entry| day.is_none_or(|day| entry.puzzle.day() == day));
+
+    for entry in entries {
+        verify_entry(entry, answers, record, &mut summary);
+    }
+
+    if summary.recorded > 0 {
+        answers.save()?;
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} missing, {} recorded",
+        summary.passed, summary.failed, summary.missing, summary.recorded
+    );
+
+    Ok(summary.failed == 0)
+}
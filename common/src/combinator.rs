@@ -0,0 +1,390 @@
+//! Parser combinators for the recurring shapes of puzzle inputs.
+//!
+//! A parser takes the rest of the input and returns what it parsed together
+//! with the new rest. The rest is always a slice of the whole input, so
+//! [`parse_all`] can turn a [`Failure`] into a positioned [`ParseError`].
+
+use std::str::FromStr;
+use crate::parse::ParseError;
+
+/// Why a parser did not match: what it `expected` instead of `token`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    pub token: &'a str,
+    pub expected: String,
+}
+
+pub type Parsed<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+/// Anything that parses a `T` off the front of the rest of the input.
+pub trait Parser<'a, T>: Fn(&'a str) -> Parsed<'a, T> {}
+
+impl<'a, T, F: Fn(&'a str) -> Parsed<'a, T>> Parser<'a, T> for F {}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+/// The word at the start of `rest`, or else its first character unless that ends the line.
+fn next_token(rest: &str) -> &str {
+    let word_length = rest.find(|c| !is_word_char(c)).unwrap_or(rest.len());
+
+    match rest.chars().next() {
+        _ if word_length > 0 => &rest[..word_length],
+        Some(c) if c != '\n' && c != '\r' => &rest[..c.len_utf8()],
+        _ => &rest[..0],
+    }
+}
+
+/// The part of `before` that has been consumed to reach `after`.
+fn consumed<'a>(before: &'a str, after: &'a str) -> &'a str {
+    &before[..before.len() - after.len()]
+}
+
+impl<'a> Failure<'a> {
+    /// Failure about the next token of `rest`.
+    pub fn at(rest: &'a str, expected: impl Into<String>) -> Self {
+        Failure { token: next_token(rest), expected: expected.into() }
+    }
+
+    /// Failures at punctuation or at the end of a line end repetitions instead
+    /// of failing them, as that is where lists stop.
+    fn is_soft(self: &Self) -> bool {
+        !self.token.starts_with(is_word_char)
+    }
+
+    pub fn into_parse_error(self, input: &str) -> ParseError {
+        ParseError::at(input, self.token, self.expected)
+    }
+}
+
+/// Runs `parser` on the whole `input`, which it has to consume completely.
+pub fn parse_all<'a, T>(input: &'a str, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+    match parser(input) {
+        Ok((value, "")) => Ok(value),
+        Ok((_, rest)) => Err(Failure::at(rest, "end of input").into_parse_error(input)),
+        Err(failure) => Err(failure.into_parse_error(input)),
+    }
+}
+
+/// Every non-overlapping match of `parser` in `input`, skipping what does not match.
+pub fn find_all<'a, T>(input: &'a str, parser: impl Parser<'a, T>) -> Vec<T> {
+    let mut found = Vec::new();
+    let mut rest = input;
+
+    while let Some(c) = rest.chars().next() {
+        match parser(rest) {
+            Ok((value, new_rest)) if new_rest.len() < rest.len() => {
+                found.push(value);
+                rest = new_rest;
+            },
+            _ => rest = &rest[c.len_utf8()..],
+        }
+    }
+
+    found
+}
+
+/// Exactly `literal`.
+pub fn tag<'a>(literal: &'static str) -> impl Parser<'a, &'a str> {
+    move |rest: &'a str| match rest.strip_prefix(literal) {
+        Some(new_rest) => Ok((&rest[..literal.len()], new_rest)),
+        None => Err(Failure::at(rest, format!("'{}'", literal.escape_default()))),
+    }
+}
+
+/// A decimal number, negative if it starts with `-`.
+pub fn number<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |rest: &'a str| {
+        let sign_length = if rest.starts_with('-') { 1 } else { 0 };
+        let digits_length = rest[sign_length..].find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len() - sign_length);
+
+        let token = &rest[..sign_length + digits_length];
+        let is_followed_by_word = rest[token.len()..].starts_with(is_word_char);
+
+        match token.parse::<T>() {
+            Ok(value) if digits_length > 0 && !is_followed_by_word => Ok((value, &rest[token.len()..])),
+            _ => Err(Failure::at(rest, "a number")),
+        }
+    }
+}
+
+/// A non-empty run of letters, digits and underscores.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    move |rest: &'a str| {
+        let length = rest.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(rest.len());
+
+        match length {
+            0 => Err(Failure::at(rest, "a word")),
+            _ => Ok((&rest[..length], &rest[length..])),
+        }
+    }
+}
+
+/// Any number of spaces and tabs, including none.
+pub fn spaces<'a>() -> impl Parser<'a, &'a str> {
+    move |rest: &'a str| {
+        let length = rest.find(|c| c != ' ' && c != '\t').unwrap_or(rest.len());
+        Ok((&rest[..length], &rest[length..]))
+    }
+}
+
+/// One character that `symbol` turns into a value.
+pub fn symbol<'a, T>(symbol: impl Fn(char) -> Option<T>, expected: &'static str) -> impl Parser<'a, T> {
+    move |rest: &'a str| match rest.chars().next() {
+        Some(c) => match symbol(c) {
+            Some(value) => Ok((value, &rest[c.len_utf8()..])),
+            None => Err(Failure { token: &rest[..c.len_utf8()], expected: expected.to_string() }),
+        },
+        None => Err(Failure::at(rest, expected)),
+    }
+}
+
+/// The value of the first of the `options` whose text the rest starts with.
+pub fn one_of<'a, T: Clone>(options: Vec<(&'static str, T)>) -> impl Parser<'a, T> {
+    let expected = {
+        let quoted: Vec<String> = options.iter().map(|(text, _)| format!("'{text}'")).collect();
+        match quoted.split_last() {
+            Some((last, [])) => last.clone(),
+            Some((last, others)) => format!("{} or {last}", others.join(", ")),
+            None => "nothing".to_string(),
+        }
+    };
+
+    move |rest: &'a str| {
+        options
+            .iter()
+            .find(|(text, _)| rest.starts_with(text))
+            .map(|(text, value)| (value.clone(), &rest[text.len()..]))
+            .ok_or_else(|| Failure::at(rest, expected.clone()))
+    }
+}
+
+/// Everything up to the end of the line, which is not consumed.
+pub fn rest_of_line<'a>() -> impl Parser<'a, &'a str> {
+    move |rest: &'a str| {
+        let length = rest.find('\n').unwrap_or(rest.len());
+        Ok((&rest[..length], &rest[length..]))
+    }
+}
+
+/// A line break, or the end of the input.
+pub fn end_of_line<'a>() -> impl Parser<'a, ()> {
+    move |rest: &'a str| match rest.strip_prefix('\n') {
+        Some(new_rest) => Ok(((), new_rest)),
+        None if rest.is_empty() => Ok(((), rest)),
+        None => Err(Failure::at(rest, "end of line")),
+    }
+}
+
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |rest: &'a str| parser(rest).map(|(value, new_rest)| (f(value), new_rest))
+}
+
+/// Fails as `expected` about everything `parser` consumed if its value does not satisfy `condition`.
+pub fn verify<'a, T>(parser: impl Parser<'a, T>, condition: impl Fn(&T) -> bool, expected: &'static str) -> impl Parser<'a, T> {
+    move |rest: &'a str| {
+        let (value, new_rest) = parser(rest)?;
+
+        match condition(&value) {
+            true => Ok((value, new_rest)),
+            false => Err(Failure { token: consumed(rest, new_rest), expected: expected.to_string() }),
+        }
+    }
+}
+
+/// The value of `parser` together with the text it consumed, for errors found later.
+pub fn spanned<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, (T, &'a str)> {
+    move |rest: &'a str| {
+        let (value, new_rest) = parser(rest)?;
+        Ok(((value, consumed(rest, new_rest)), new_rest))
+    }
+}
+
+pub fn pair<'a, A, B>(first: impl Parser<'a, A>, second: impl Parser<'a, B>) -> impl Parser<'a, (A, B)> {
+    move |rest: &'a str| {
+        let (a, rest) = first(rest)?;
+        let (b, rest) = second(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// `first`, then `second`, keeping only the value of `second`.
+pub fn preceded<'a, A, B>(first: impl Parser<'a, A>, second: impl Parser<'a, B>) -> impl Parser<'a, B> {
+    map(pair(first, second), |(_, b)| b)
+}
+
+/// `first`, then `second`, keeping only the value of `first`.
+pub fn terminated<'a, A, B>(first: impl Parser<'a, A>, second: impl Parser<'a, B>) -> impl Parser<'a, A> {
+    map(pair(first, second), |(a, _)| a)
+}
+
+pub fn delimited<'a, A, B, C>(open: impl Parser<'a, A>, inner: impl Parser<'a, B>, close: impl Parser<'a, C>) -> impl Parser<'a, B> {
+    preceded(open, terminated(inner, close))
+}
+
+/// `first`, or else `second`; if both fail at the same token, both expectations are reported.
+pub fn or<'a, T>(first: impl Parser<'a, T>, second: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |rest: &'a str| match first(rest) {
+        Ok(parsed) => Ok(parsed),
+        Err(first_failure) => match second(rest) {
+            Ok(parsed) => Ok(parsed),
+            Err(second_failure) if first_failure.token.as_ptr() == second_failure.token.as_ptr() => Err(Failure {
+                expected: format!("{} or {}", first_failure.expected, second_failure.expected),
+                ..second_failure
+            }),
+            Err(second_failure) if first_failure.token.as_ptr() > second_failure.token.as_ptr() => Err(first_failure),
+            Err(second_failure) => Err(second_failure),
+        },
+    }
+}
+
+/// `parser` as often as it matches, at least once; see [`Failure`] for where repetitions stop.
+pub fn many1<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |rest: &'a str| {
+        let (first, mut rest) = parser(rest)?;
+        let mut values = vec![first];
+
+        loop {
+            match parser(rest) {
+                Ok((value, new_rest)) if new_rest.len() < rest.len() => {
+                    values.push(value);
+                    rest = new_rest;
+                },
+                Err(failure) if !failure.is_soft() => return Err(failure),
+                _ => return Ok((values, rest)),
+            }
+        }
+    }
+}
+
+/// At least one `item`, separated by `separator`.
+pub fn separated<'a, T, S>(item: impl Parser<'a, T>, separator: impl Parser<'a, S>) -> impl Parser<'a, Vec<T>> {
+    move |rest: &'a str| {
+        let (first, mut rest) = item(rest)?;
+        let mut values = vec![first];
+
+        loop {
+            let next = separator(rest).and_then(|(_, after_separator)| item(after_separator));
+
+            match next {
+                Ok((value, new_rest)) => {
+                    values.push(value);
+                    rest = new_rest;
+                },
+                Err(failure) if !failure.is_soft() => return Err(failure),
+                Err(_) => return Ok((values, rest)),
+            }
+        }
+    }
+}
+
+/// Whitespace-separated numbers, e.g. `1 -2   3`, with optional leading spaces.
+pub fn numbers<'a, T: FromStr>() -> impl Parser<'a, Vec<T>> {
+    many1(preceded(spaces(), number()))
+}
+
+/// Items separated by commas, e.g. `75,47,61` or `a, b`.
+pub fn comma_separated<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    separated(item, pair(tag(","), spaces()))
+}
+
+/// Two items around `separator`, e.g. `47|53`.
+pub fn separated_pair<'a, A, S, B>(first: impl Parser<'a, A>, separator: impl Parser<'a, S>, second: impl Parser<'a, B>) -> impl Parser<'a, (A, B)> {
+    pair(terminated(first, separator), second)
+}
+
+/// A labelled line such as `Card 1: <body>`: returns the parsed `id` and `body`.
+pub fn labelled<'a, I, T>(label: &'static str, id: impl Parser<'a, I>, body: impl Parser<'a, T>) -> impl Parser<'a, (I, T)> {
+    pair(
+        delimited(pair(tag(label), spaces()), id, pair(tag(":"), spaces())),
+        body,
+    )
+}
+
+/// `key = value`, with optional spaces around the `=`.
+pub fn assignment<'a, K, V>(key: impl Parser<'a, K>, value: impl Parser<'a, V>) -> impl Parser<'a, (K, V)> {
+    separated_pair(key, delimited(spaces(), tag("="), spaces()), value)
+}
+
+/// `(a, b)`.
+pub fn parenthesized_pair<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, (T, T)> {
+    let separator = pair(tag(","), spaces());
+
+    move |rest: &'a str| {
+        let (_, rest) = tag("(")(rest)?;
+        let (first, rest) = item(rest)?;
+        let (_, rest) = separator(rest)?;
+        let (second, rest) = item(rest)?;
+        let (_, rest) = tag(")")(rest)?;
+        Ok(((first, second), rest))
+    }
+}
+
+/// One line or more, each parsed by `line` up to its line break; stops at a
+/// blank line or the end of the input.
+pub fn lines<'a, T>(line: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    let line = terminated(line, end_of_line());
+
+    move |rest: &'a str| {
+        let (first, mut rest) = line(rest)?;
+        let mut values = vec![first];
+
+        while !rest.is_empty() && !rest.starts_with('\n') {
+            let (value, new_rest) = line(rest)?;
+            values.push(value);
+            rest = new_rest;
+        }
+
+        Ok((values, rest))
+    }
+}
+
+/// Sections separated by blank lines; `section` consumes its last line break.
+pub fn sections<'a, T>(section: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    separated(section, tag("\n"))
+}
+
+#[test]
+fn parse_lists() {
+    assert_eq!(parse_all("1 -2   3", numbers::<i32>()), Ok(vec![1, -2, 3]));
+    assert_eq!(parse_all("75,47,61", comma_separated(number::<u32>())), Ok(vec![75, 47, 61]));
+    assert_eq!(parse_all("47|53", separated_pair(number::<u32>(), tag("|"), number::<u32>())), Ok((47, 53)));
+    assert_eq!(parse_all("AAA = (BBB, CCC)", assignment(word(), parenthesized_pair(word()))), Ok(("AAA", ("BBB", "CCC"))));
+
+    let card = labelled("Card", number::<u32>(), separated_pair(numbers::<u32>(), pair(spaces(), tag("|")), numbers::<u32>()));
+    assert_eq!(parse_all("Card  1: 41 48 | 83 86", card), Ok((1, (vec![41, 48], vec![83, 86]))));
+}
+
+#[test]
+fn parse_sections() {
+    let section = pair(terminated(word(), pair(tag(":"), end_of_line())), lines(numbers::<u32>()));
+    let parsed = parse_all("a:\n1 2\n3\n\nb:\n4\n", sections(section)).unwrap();
+
+    assert_eq!(parsed, vec![("a", vec![vec![1, 2], vec![3]]), ("b", vec![vec![4]])]);
+}
+
+#[test]
+fn failure_positions() {
+    let error = parse_all("1 2\n3 x4\n", lines(numbers::<u32>())).err().unwrap();
+    assert_eq!(error.to_string(), "2:3: expected a number, found 'x4'");
+
+    let error = parse_all("1 2;\n", lines(numbers::<u32>())).err().unwrap();
+    assert_eq!(error.to_string(), "1:4: expected end of line, found ';'");
+
+    let color = one_of(vec![("red", 0), ("green", 1), ("blue", 2)]);
+    let error = parse_all("3 purple", preceded(pair(number::<u32>(), spaces()), color)).err().unwrap();
+    assert_eq!(error.to_string(), "1:3: expected 'red', 'green' or 'blue', found 'purple'");
+
+    let odd = verify(comma_separated(number::<u32>()), |pages| pages.len() % 2 == 1, "an odd number of pages");
+    assert_eq!(parse_all("75,47", odd).err().unwrap().to_string(), "1:1: expected an odd number of pages, found '75,47'");
+
+    let error = parse_all("do", or(tag("do()"), tag("don't()"))).err().unwrap();
+    assert_eq!(error.expected, "'do()' or 'don\\'t()'");
+}
+
+#[test]
+fn find_matches() {
+    let mul = delimited(tag("mul("), separated_pair(number::<u32>(), tag(","), number::<u32>()), tag(")"));
+    assert_eq!(find_all("xmul(2,4)%mul[3,7]!mul(5,5)mul(32,64]", mul), vec![(2, 4), (5, 5)]);
+}
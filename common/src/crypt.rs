@@ -0,0 +1,117 @@
+use std::fmt;
+use std::path::PathBuf;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+
+pub const DEFAULT_KEY_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../.input-key");
+
+/// Suffix of encrypted resources, e.g. `res/input.enc` for `res/input`.
+pub const ENCRYPTED_SUFFIX: &str = ".enc";
+
+const MAGIC: &[u8] = b"aoc-input-v1\n";
+const NONCE_LENGTH: usize = 12;
+
+/// Key of the encrypted puzzle inputs, which must not be redistributed in plain text.
+///
+/// Encrypted files are `MAGIC | nonce | ciphertext`, sealed with ChaCha20-Poly1305,
+/// so a wrong key or a damaged file is detected instead of yielding garbage.
+#[derive(Clone, PartialEq, Eq)]
+pub struct InputKey([u8; 32]);
+
+impl fmt::Debug for InputKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "InputKey(..)")
+    }
+}
+
+impl InputKey {
+    pub fn generate() -> Self {
+        InputKey(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self, String> {
+        let hex = hex.trim();
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err("expected a key of 64 hex digits".to_string());
+        }
+
+        let mut key = [0; 32];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).map_err(|_| "expected a key of 64 hex digits".to_string())?;
+        }
+
+        Ok(InputKey(key))
+    }
+
+    pub fn to_hex(self: &Self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    /// Path of the key file: `AOC_INPUT_KEY_FILE`, or the git-ignored `.input-key` of the workspace.
+    pub fn key_file() -> PathBuf {
+        match std::env::var_os("AOC_INPUT_KEY_FILE") {
+            Some(path) => PathBuf::from(path),
+            None => PathBuf::from(DEFAULT_KEY_FILE),
+        }
+    }
+
+    /// The key in hex from `AOC_INPUT_KEY`, or else from the key file; `None` if there is neither.
+    pub fn load() -> Result<Option<Self>, String> {
+        if let Some(hex) = std::env::var("AOC_INPUT_KEY").ok().filter(|hex| !hex.is_empty()) {
+            return Self::from_hex(&hex).map(Some).map_err(|error| format!("AOC_INPUT_KEY: {error}"));
+        }
+
+        let path = Self::key_file();
+        match std::fs::read_to_string(&path) {
+            Ok(hex) => Self::from_hex(&hex).map(Some).map_err(|error| format!("{}: {error}", path.display())),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(format!("{}: {error}", path.display())),
+        }
+    }
+
+    pub fn encrypt(self: &Self, plaintext: &str) -> Vec<u8> {
+        let cipher = ChaCha20Poly1305::new(&self.0.into());
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = cipher.encrypt(&nonce, plaintext.as_bytes()).expect("encrypting into memory cannot fail");
+
+        [MAGIC, nonce.as_slice(), &ciphertext].concat()
+    }
+
+    pub fn decrypt(self: &Self, data: &[u8]) -> Result<String, String> {
+        let data = data.strip_prefix(MAGIC).ok_or("not an encrypted input")?;
+        if data.len() < NONCE_LENGTH {
+            return Err("encrypted input is truncated".to_string());
+        }
+
+        let (nonce, ciphertext) = data.split_at(NONCE_LENGTH);
+        let cipher = ChaCha20Poly1305::new(&self.0.into());
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| "wrong key or damaged encrypted input".to_string())?;
+
+        String::from_utf8(plaintext).map_err(|_| "decrypted input is not UTF-8".to_string())
+    }
+}
+
+#[test]
+fn encryption_round_trip() {
+    let key = InputKey::generate();
+    let encrypted = key.encrypt("1 2 3\n");
+
+    assert!(encrypted.starts_with(MAGIC));
+    assert!(!encrypted.windows(5).any(|w| w == b"1 2 3"));
+    assert_eq!(key.decrypt(&encrypted), Ok("1 2 3\n".to_string()));
+
+    assert!(InputKey::generate().decrypt(&encrypted).is_err());
+
+    let mut damaged = encrypted.clone();
+    *damaged.last_mut().unwrap() ^= 1;
+    assert!(key.decrypt(&damaged).is_err());
+}
+
+#[test]
+fn key_hex_round_trip() {
+    let key = InputKey::generate();
+    assert_eq!(InputKey::from_hex(&key.to_hex()), Ok(key));
+    assert!(InputKey::from_hex("abc").is_err());
+}
@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use crate::input::read_resource;
+use crate::solution::{Error, Part, Puzzle};
+
+/// Expected answers of an input, read from a sidecar file next to it, e.g.
+/// `res/example.answers` for `res/example`:
+///
+/// ```text
+/// part_1=142
+/// part_2=
+/// ```
+///
+/// An empty value means the answer is not known yet.
+pub fn parse_expected_answers(content: &str) -> Result<BTreeMap<Part, String>, String> {
+    let mut answers = BTreeMap::new();
+
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let part = line
+            .split_once('=')
+            .and_then(|(key, answer)| {
+                let part = key.trim().strip_prefix("part_")?.parse().ok().and_then(Part::from_number)?;
+                Some((part, answer.trim()))
+            });
+
+        match part {
+            Some((_, "")) => (),
+            Some((part, answer)) => {
+                answers.insert(part, answer.to_string());
+            },
+            None => return Err(format!("line {}: expected part_<1|2>=<answer>, got '{line}'", i + 1)),
+        }
+    }
+
+    Ok(answers)
+}
+
+/// Test helper: solves `part` of the input `input_name` in `resources` and
+/// compares it with the answer in its `.answers` sidecar file. Does nothing
+/// while the expected answer is not filled in.
+pub fn check_example(puzzle: &dyn Puzzle, resources: &str, input_name: &str, part: Part) {
+    let sidecar = Path::new(resources).join(format!("{input_name}.answers"));
+
+    let content = std::fs::read_to_string(&sidecar).unwrap_or_else(|error| panic!("{}: {error}", sidecar.display()));
+    let expected = parse_expected_answers(&content).unwrap_or_else(|error| panic!("{}: {error}", sidecar.display()));
+
+    let Some(expected) = expected.get(&part) else {
+        return;
+    };
+
+    let input = read_resource(Path::new(resources), input_name).unwrap_or_else(|error| panic!("{error}"));
+
+    match puzzle.solve(&input, part) {
+        Ok(answer) => assert_eq!(&answer.to_string(), expected, "part {part} of {input_name}"),
+        Err(Error::Unsolved(_)) => panic!("part {part} of {input_name} is expected to be {expected}, but is not solved"),
+        Err(error) => panic!("{}", error.with_input_name(input_name)),
+    }
+}
+
+#[test]
+fn parse_sidecar() {
+    let answers = parse_expected_answers("# comment\npart_1=142\npart_2=\n").unwrap();
+    assert_eq!(answers.get(&Part::One).map(|a| a.as_str()), Some("142"));
+    assert_eq!(answers.get(&Part::Two), None);
+
+    assert!(parse_expected_answers("part_3=1\n").is_err());
+}
@@ -0,0 +1,234 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point on the plane of a puzzle. On grids `x` is the column and grows to
+/// the east, `y` is the row and grows to the south.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// The difference between two points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Vector {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// One of the four directions on a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// The point at `column` and `row` of a grid; `None` if it does not fit into `i64`.
+    pub fn from_unsigned(column: usize, row: usize) -> Option<Self> {
+        Some(Point { x: i64::try_from(column).ok()?, y: i64::try_from(row).ok()? })
+    }
+
+    /// `(column, row)` of the point on a grid; `None` for negative coordinates.
+    pub fn to_unsigned(self: &Self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+
+    /// Number of orthogonal steps between two points.
+    pub fn manhattan_distance(self: &Self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Number of steps between two points when diagonal steps are allowed.
+    pub fn chebyshev_distance(self: &Self, other: Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl Vector {
+    pub const ZERO: Vector = Vector { x: 0, y: 0 };
+    pub const NORTH: Vector = Vector { x: 0, y: -1 };
+    pub const EAST: Vector = Vector { x: 1, y: 0 };
+    pub const SOUTH: Vector = Vector { x: 0, y: 1 };
+    pub const WEST: Vector = Vector { x: -1, y: 0 };
+    pub const NORTH_EAST: Vector = Vector { x: 1, y: -1 };
+    pub const SOUTH_EAST: Vector = Vector { x: 1, y: 1 };
+    pub const SOUTH_WEST: Vector = Vector { x: -1, y: 1 };
+    pub const NORTH_WEST: Vector = Vector { x: -1, y: -1 };
+
+    /// The eight steps to neighbouring points, clockwise from north.
+    pub const AROUND: [Vector; 8] = [
+        Vector::NORTH,
+        Vector::NORTH_EAST,
+        Vector::EAST,
+        Vector::SOUTH_EAST,
+        Vector::SOUTH,
+        Vector::SOUTH_WEST,
+        Vector::WEST,
+        Vector::NORTH_WEST,
+    ];
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Vector { x, y }
+    }
+
+    pub fn manhattan_length(self: &Self) -> u64 {
+        Point::ORIGIN.manhattan_distance(Point::ORIGIN + *self)
+    }
+
+    pub fn chebyshev_length(self: &Self) -> u64 {
+        Point::ORIGIN.chebyshev_distance(Point::ORIGIN + *self)
+    }
+}
+
+impl Direction {
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    pub fn turn_right(self: &Self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn_left(self: &Self) -> Self {
+        self.opposite().turn_right()
+    }
+
+    pub fn opposite(self: &Self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The unit step in this direction.
+    pub fn to_vector(self: &Self) -> Vector {
+        match self {
+            Direction::North => Vector::NORTH,
+            Direction::East => Vector::EAST,
+            Direction::South => Vector::SOUTH,
+            Direction::West => Vector::WEST,
+        }
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(direction: Direction) -> Self {
+        direction.to_vector()
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Vector) -> Point {
+        Point { x: self.x + rhs.x, y: self.y + rhs.y }
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Vector) -> Point {
+        self + -rhs
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, rhs: Vector) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub<Point> for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Point) -> Vector {
+        Vector { x: self.x - rhs.x, y: self.y - rhs.y }
+    }
+}
+
+impl Add<Vector> for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Vector) -> Vector {
+        Vector { x: self.x + rhs.x, y: self.y + rhs.y }
+    }
+}
+
+impl Sub<Vector> for Vector {
+    type Output = Vector;
+
+    fn sub(self, rhs: Vector) -> Vector {
+        self + -rhs
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector { x: -self.x, y: -self.y }
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: i64) -> Vector {
+        Vector { x: self.x * rhs, y: self.y * rhs }
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[test]
+fn point_arithmetic() {
+    let (a, b) = (Point::new(1, 2), Point::new(4, -2));
+
+    assert_eq!(b - a, Vector::new(3, -4));
+    assert_eq!(a + (b - a), b);
+    assert_eq!(a - Vector::NORTH * 2, Point::new(1, 4));
+    assert_eq!(a.manhattan_distance(b), 7);
+    assert_eq!(a.chebyshev_distance(b), 4);
+    assert_eq!((b - a).manhattan_length(), 7);
+}
+
+#[test]
+fn unsigned_conversion() {
+    assert_eq!(Point::from_unsigned(3, 5), Some(Point::new(3, 5)));
+    assert_eq!(Point::from_unsigned(usize::MAX, 0), None);
+    assert_eq!(Point::new(3, 5).to_unsigned(), Some((3, 5)));
+    assert_eq!(Point::new(3, -1).to_unsigned(), None);
+}
+
+#[test]
+fn turns() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.opposite().to_vector(), -direction.to_vector());
+    }
+
+    assert_eq!(Direction::North.turn_right(), Direction::East);
+    assert_eq!(Direction::North.turn_left(), Direction::West);
+    assert_eq!(Vector::from(Direction::South), Vector::SOUTH);
+}
@@ -0,0 +1,104 @@
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use crate::crypt::{InputKey, ENCRYPTED_SUFFIX};
+
+/// Where the text handed to a day's parser comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The personal puzzle input, `res/input` of the day.
+    Input,
+    /// A named example of the day: `None` is `res/example`, `Some("2")` is `res/example_2`.
+    Example(Option<String>),
+    /// Any file on disk.
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Short name identifying the input, e.g. `input`, `example_2` or the file name.
+    pub fn name(self: &Self) -> String {
+        match self {
+            InputSource::Input => "input".to_string(),
+            InputSource::Example(None) => "example".to_string(),
+            InputSource::Example(Some(name)) => format!("example_{name}"),
+            InputSource::File(path) => path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.display().to_string()),
+            InputSource::Stdin => "stdin".to_string(),
+        }
+    }
+
+    /// Loads the input; resource files are looked up in the day's `resources` directory.
+    pub fn read(self: &Self, resources: &Path) -> io::Result<String> {
+        let path = match self {
+            InputSource::Input | InputSource::Example(_) => return read_resource(resources, &self.name()),
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => {
+                let mut buffer = String::new();
+                io::stdin().read_to_string(&mut buffer)?;
+                return Ok(buffer);
+            },
+        };
+
+        std::fs::read_to_string(&path)
+            .map_err(|error| io::Error::new(error.kind(), format!("{}: {error}", path.display())))
+    }
+}
+
+/// Reads the resource `name` of a day: the plain file, or else its encrypted
+/// `.enc` counterpart, decrypted with the [`InputKey`].
+pub fn read_resource(resources: &Path, name: &str) -> io::Result<String> {
+    read_resource_with_key(resources, name, InputKey::load)
+}
+
+fn read_resource_with_key(
+    resources: &Path,
+    name: &str,
+    load_key: impl FnOnce() -> Result<Option<InputKey>, String>,
+) -> io::Result<String> {
+    let path = resources.join(name);
+    let error_at = |path: &Path, kind: io::ErrorKind, error: &dyn std::fmt::Display| {
+        io::Error::new(kind, format!("{}: {error}", path.display()))
+    };
+
+    match std::fs::read_to_string(&path) {
+        Ok(content) => return Ok(content),
+        Err(error) if error.kind() != io::ErrorKind::NotFound => return Err(error_at(&path, error.kind(), &error)),
+        Err(_) => (),
+    }
+
+    let encrypted_path = resources.join(format!("{name}{ENCRYPTED_SUFFIX}"));
+    let data = match std::fs::read(&encrypted_path) {
+        Ok(data) => data,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Err(error_at(&path, error.kind(), &error)),
+        Err(error) => return Err(error_at(&encrypted_path, error.kind(), &error)),
+    };
+
+    let key = load_key()
+        .map_err(io::Error::other)?
+        .ok_or_else(|| {
+            let message = format!("no key, set AOC_INPUT_KEY or create {}", InputKey::key_file().display());
+            error_at(&encrypted_path, io::ErrorKind::NotFound, &message)
+        })?;
+
+    key.decrypt(&data).map_err(|error| error_at(&encrypted_path, io::ErrorKind::InvalidData, &error))
+}
+
+#[test]
+fn encrypted_resource_is_decrypted() {
+    let resources = std::env::temp_dir().join(format!("aoc-resources-{}", std::process::id()));
+    std::fs::create_dir_all(&resources).unwrap();
+
+    let key = InputKey::generate();
+    std::fs::write(resources.join("input.enc"), key.encrypt("secret\n")).unwrap();
+    std::fs::write(resources.join("example"), "plain\n").unwrap();
+
+    assert_eq!(read_resource_with_key(&resources, "input", || Ok(Some(key.clone()))).unwrap(), "secret\n");
+    assert_eq!(read_resource_with_key(&resources, "example", || Ok(None)).unwrap(), "plain\n");
+    assert!(read_resource_with_key(&resources, "input", || Ok(None)).unwrap_err().to_string().contains("no key"));
+    assert!(read_resource_with_key(&resources, "input", || Ok(Some(InputKey::generate()))).is_err());
+    assert_eq!(read_resource_with_key(&resources, "missing", || Ok(None)).unwrap_err().kind(), io::ErrorKind::NotFound);
+
+    std::fs::remove_dir_all(&resources).unwrap();
+}
@@ -0,0 +1,19 @@
+pub mod combinator;
+mod crypt;
+mod example;
+mod geometry;
+mod input;
+mod normalize;
+pub mod number_theory;
+mod parse;
+mod runner;
+mod solution;
+
+pub use crypt::{InputKey, ENCRYPTED_SUFFIX};
+pub use example::{check_example, parse_expected_answers};
+pub use geometry::{Direction, Point, Vector};
+pub use input::{read_resource, InputSource};
+pub use normalize::normalize;
+pub use parse::{ensure_rectangular, parse_number, ParseError};
+pub use runner::{main, run, RunArgs, INPUT_USAGE};
+pub use solution::{Answer, Error, Part, PhaseTimes, Puzzle, Solution};
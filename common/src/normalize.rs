@@ -0,0 +1,41 @@
+const BYTE_ORDER_MARK: char = '\u{feff}';
+
+/// Brings an input into the form the parsers expect, whatever editor saved it:
+/// no byte order mark, `\n` line endings and no trailing blank lines, but a
+/// final line break.
+///
+/// With `collapse_whitespace`, runs of spaces and tabs also become a single
+/// space and lines lose leading and trailing blanks, for days whose input
+/// aligns columns with spaces.
+pub fn normalize(input: &str, collapse_whitespace: bool) -> String {
+    let input = input.strip_prefix(BYTE_ORDER_MARK).unwrap_or(input);
+
+    let mut lines: Vec<String> = input
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .map(|line| match collapse_whitespace {
+            true => line.split([' ', '\t']).filter(|word| !word.is_empty()).collect::<Vec<&str>>().join(" "),
+            false => line.to_string(),
+        })
+        .collect();
+
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+#[test]
+fn normalize_windows_input() {
+    assert_eq!(normalize("\u{feff}a b\r\n\r\nc\r\n\r\n  \r\n", false), "a b\n\nc\n");
+    assert_eq!(normalize("a\nb", false), "a\nb\n");
+    assert_eq!(normalize("  #  \n", false), "  #  \n");
+    assert_eq!(normalize("\n\n", false), "");
+}
+
+#[test]
+fn normalize_collapses_whitespace() {
+    assert_eq!(normalize("Time:      7  15\t 30\r\n", true), "Time: 7 15 30\n");
+    assert_eq!(normalize("  3   4  \n", true), "3 4\n");
+}
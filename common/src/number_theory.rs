@@ -0,0 +1,265 @@
+//! Integer arithmetic in `i128`, which leaves room for products of `i64`s;
+//! operations that could still overflow return `None` instead.
+
+use std::fmt;
+
+/// Greatest common divisor, never negative; `gcd(0, 0)` is 0.
+pub fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a as i128
+}
+
+/// Least common multiple, never negative; 0 if either is 0.
+pub fn lcm(a: i128, b: i128) -> Option<i128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b).map(i128::abs)
+}
+
+/// Greatest common divisor of all values, 0 if there are none.
+pub fn gcd_all(values: impl IntoIterator<Item = i128>) -> i128 {
+    values.into_iter().fold(0, gcd)
+}
+
+/// Least common multiple of all values, 1 if there are none.
+pub fn lcm_all(values: impl IntoIterator<Item = i128>) -> Option<i128> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    match old_r < 0 {
+        true => (-old_r, -old_x, -old_y),
+        false => (old_r, old_x, old_y),
+    }
+}
+
+/// `a * b` modulo `m`, in `0..m`, without overflowing for any `m > 0`.
+pub fn mul_mod(a: i128, b: i128, m: i128) -> i128 {
+    let (mut a, mut b) = (a.rem_euclid(m), b.rem_euclid(m));
+
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    // Double and add; both stay below m, so the sums fit.
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = (result + (a - m)).rem_euclid(m);
+        }
+        a = (a - (m - a)).rem_euclid(m);
+        b >>= 1;
+    }
+    result
+}
+
+/// `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    assert!(m > 0, "modulus {m} is not positive");
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    match g == 1 {
+        true => Some(x.rem_euclid(m)),
+        false => None,
+    }
+}
+
+/// Chinese remainder theorem for moduli that need not be coprime: the
+/// `(residue, modulus)` that the solutions of all `x ≡ residue (mod modulus)`
+/// share. `None` if the congruences contradict each other or the combined
+/// modulus does not fit.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    let mut combined: (i128, i128) = (0, 1);
+
+    for (residue, modulus) in congruences {
+        assert!(modulus > 0, "modulus {modulus} is not positive");
+
+        let (r1, m1) = combined;
+        let r2 = residue.rem_euclid(modulus);
+
+        // r1 + m1 * k ≡ r2 (mod modulus), solvable iff g divides the difference.
+        let (g, p, _) = extended_gcd(m1, modulus);
+        let difference = r2 - r1;
+        if difference % g != 0 {
+            return None;
+        }
+
+        let step = modulus / g;
+        let k = mul_mod(difference / g, p, step);
+        let m = m1.checked_mul(step)?;
+
+        combined = ((r1 + mul_mod(m1, k, m)).rem_euclid(m), m);
+    }
+
+    Some(combined)
+}
+
+/// An exact fraction, kept reduced with a positive denominator so equal
+/// values are equal. Arithmetic is checked and yields `None` on overflow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numerator: 0, denominator: 1 };
+    pub const ONE: Rational = Rational { numerator: 1, denominator: 1 };
+
+    /// `numerator / denominator`, `None` if the denominator is 0 or the reduced fraction does not fit.
+    pub fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let g = gcd(numerator, denominator);
+        let (numerator, denominator) = (numerator / g, denominator / g);
+
+        match denominator < 0 {
+            true => Some(Rational { numerator: numerator.checked_neg()?, denominator: denominator.checked_neg()? }),
+            false => Some(Rational { numerator, denominator }),
+        }
+    }
+
+    pub fn numerator(self: &Self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(self: &Self) -> i128 {
+        self.denominator
+    }
+
+    /// The value, if it is a whole number.
+    pub fn to_integer(self: &Self) -> Option<i128> {
+        match self.denominator == 1 {
+            true => Some(self.numerator),
+            false => None,
+        }
+    }
+
+    pub fn checked_add(self: &Self, other: &Rational) -> Option<Rational> {
+        let g = gcd(self.denominator, other.denominator);
+        let (a, b) = (self.denominator / g, other.denominator / g);
+
+        let numerator = self.numerator.checked_mul(b)?.checked_add(other.numerator.checked_mul(a)?)?;
+        Rational::new(numerator, self.denominator.checked_mul(b)?)
+    }
+
+    pub fn checked_sub(self: &Self, other: &Rational) -> Option<Rational> {
+        self.checked_add(&Rational { numerator: other.numerator.checked_neg()?, denominator: other.denominator })
+    }
+
+    pub fn checked_mul(self: &Self, other: &Rational) -> Option<Rational> {
+        // Cancelling crosswise first keeps the products small.
+        let (g1, g2) = (gcd(self.numerator, other.denominator).max(1), gcd(other.numerator, self.denominator).max(1));
+
+        let numerator = (self.numerator / g1).checked_mul(other.numerator / g2)?;
+        let denominator = (self.denominator / g2).checked_mul(other.denominator / g1)?;
+        Rational::new(numerator, denominator)
+    }
+
+    /// `None` also when dividing by zero.
+    pub fn checked_div(self: &Self, other: &Rational) -> Option<Rational> {
+        let inverse = Rational::new(other.denominator, other.numerator)?;
+        self.checked_mul(&inverse)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Rational { numerator: value, denominator: 1 }
+    }
+}
+
+impl fmt::Display for Rational {
+    /// `n` for whole numbers, `n/d` otherwise.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.denominator {
+            1 => write!(f, "{}", self.numerator),
+            denominator => write!(f, "{}/{denominator}", self.numerator),
+        }
+    }
+}
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!((gcd(12, 18), gcd(-12, 18), gcd(0, 5), gcd(0, 0)), (6, 6, 5, 0));
+    assert_eq!((lcm(4, 6), lcm(-4, 6), lcm(0, 6)), (Some(12), Some(12), Some(0)));
+    assert_eq!(lcm(i128::MAX, i128::MAX - 1), None);
+    assert_eq!(gcd_all([12, 18, 27]), 3);
+    assert_eq!(lcm_all([2, 3, 4, 5]), Some(60));
+    assert_eq!(lcm_all([]), Some(1));
+}
+
+#[test]
+fn extended_euclid_and_inverse() {
+    for (a, b) in [(240, 46), (-7, 5), (0, 9), (9, 0), (17, -51)] {
+        let (g, x, y) = extended_gcd(a, b);
+        assert_eq!((g, a * x + b * y), (gcd(a, b), g), "{a}, {b}");
+    }
+
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(-3, 11), Some(7));
+    assert_eq!(mod_inverse(6, 9), None);
+    assert_eq!(mul_mod(i128::MAX - 1, i128::MAX - 2, i128::MAX), 2);
+}
+
+#[test]
+fn chinese_remainders() {
+    assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+    assert_eq!(crt([(1, 4), (2, 6)]), None);
+    assert_eq!(crt([]), Some((0, 1)));
+
+    let big = (1i128 << 61) - 1;
+    assert_eq!(crt([(5, big), (7, big - 2)]).map(|(x, m)| (x % big, x % (big - 2), m)), Some((5, 7, big * (big - 2))));
+    assert_eq!(crt([(0, big), (0, big - 2), (0, big - 4)]), None);
+
+    for a in 1..30 {
+        for b in 1..30 {
+            for (r, s) in [(0, 0), (1, 0), (a - 1, b / 2)] {
+                let brute = (0..a * b).find(|x| x % a == r % a && x % b == s % b);
+                let expected = brute.map(|x| (x, lcm(a, b).unwrap()));
+                assert_eq!(crt([(r, a), (s, b)]), expected, "x ≡ {r} (mod {a}), x ≡ {s} (mod {b})");
+            }
+        }
+    }
+}
+
+#[test]
+fn rational_arithmetic() {
+    let r = |numerator, denominator| Rational::new(numerator, denominator).unwrap();
+
+    assert_eq!(r(6, -4), r(-3, 2));
+    assert_eq!((r(-3, 2).numerator(), r(-3, 2).denominator()), (-3, 2));
+    assert_eq!(Rational::new(1, 0), None);
+
+    assert_eq!(r(1, 6).checked_add(&r(1, 3)), Some(r(1, 2)));
+    assert_eq!(r(1, 6).checked_sub(&r(1, 3)), Some(r(-1, 6)));
+    assert_eq!(r(2, 3).checked_mul(&r(9, 4)), Some(r(3, 2)));
+    assert_eq!(r(2, 3).checked_div(&r(4, 9)), Some(r(3, 2)));
+    assert_eq!(r(2, 3).checked_div(&Rational::ZERO), None);
+    assert_eq!(r(6, 3).to_integer(), Some(2));
+    assert_eq!((r(7, 2).to_integer(), r(7, 2).to_string(), Rational::from(-4).to_string()), (None, "7/2".to_string(), "-4".to_string()));
+
+    let huge = Rational::from(i128::MAX);
+    assert_eq!(huge.checked_add(&Rational::ONE), None);
+    assert_eq!(huge.checked_mul(&r(1, 2)).and_then(|half| half.checked_mul(&Rational::from(2))), Some(huge));
+    assert_eq!(huge.checked_mul(&huge), None);
+}
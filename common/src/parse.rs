@@ -0,0 +1,155 @@
+use std::fmt;
+use std::str::FromStr;
+
+const MAX_FOUND_LENGTH: usize = 24;
+
+/// Where and why a day's parser rejected its input.
+///
+/// Line and column are 1-based, the column counts characters. The name of the
+/// input is unknown to the parser and is filled in by the runner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub input: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+/// Byte offset of `inner` within `outer`, if `inner` is a slice of `outer`.
+fn offset_in(outer: &str, inner: &str) -> Option<usize> {
+    let outer_start = outer.as_ptr() as usize;
+    let inner_start = inner.as_ptr() as usize;
+
+    if outer_start <= inner_start && inner_start + inner.len() <= outer_start + outer.len() {
+        Some(inner_start - outer_start)
+    }
+    else {
+        None
+    }
+}
+
+fn describe(token: &str, rest: &str) -> String {
+    if !token.is_empty() {
+        let mut found: String = token.chars().take(MAX_FOUND_LENGTH).collect();
+        if token.chars().count() > MAX_FOUND_LENGTH {
+            found.push('…');
+        }
+        return format!("'{found}'");
+    }
+
+    match rest.chars().next() {
+        None => "end of input".to_string(),
+        Some('\n') | Some('\r') => "end of line".to_string(),
+        Some(c) => format!("'{c}'"),
+    }
+}
+
+impl ParseError {
+    /// Error about `token`, which has to be a slice of `input`; an empty token
+    /// points between two characters, e.g. at a missing value.
+    pub fn at(input: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = offset_in(input, token);
+        debug_assert!(offset.is_some(), "token is not a slice of the input");
+        let offset = offset.unwrap_or(0);
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = input[line_start..offset].chars().count() + 1;
+
+        ParseError {
+            input: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: describe(token, &input[offset..]),
+        }
+    }
+
+    /// Error about something missing at the very end of `input`.
+    pub fn at_end(input: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], expected)
+    }
+
+    pub fn with_input_name(self, name: impl Into<String>) -> Self {
+        ParseError { input: Some(name.into()), ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(input) = &self.input {
+            write!(f, "{input}:")?;
+        }
+        write!(f, "{}:{}: expected {}, found {}", self.line, self.column, self.expected, self.found)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, as a number.
+pub fn parse_number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token.parse::<T>().map_err(|_| ParseError::at(input, token, "a number"))
+}
+
+/// Checks that all lines of `input` have the same number of characters, as a
+/// grid needs, and returns that width.
+pub fn ensure_rectangular(input: &str) -> Result<usize, ParseError> {
+    let width = input.lines().next().map(|line| line.chars().count()).unwrap_or(0);
+
+    for line in input.lines() {
+        if line.chars().count() != width {
+            let token = match line.char_indices().nth(width) {
+                Some((i, _)) => &line[i..],
+                None => &line[line.len()..],
+            };
+            return Err(ParseError::at(input, token, format!("rows of {width} characters")));
+        }
+    }
+
+    Ok(width)
+}
+
+#[test]
+fn error_position() {
+    let input = "12 34\n56 x8\n";
+    let token = &input[9..11];
+
+    let error = ParseError::at(input, token, "a number");
+    assert_eq!((error.line, error.column), (2, 4));
+    assert_eq!(error.to_string(), "2:4: expected a number, found 'x8'");
+    assert_eq!(error.with_input_name("example").to_string(), "example:2:4: expected a number, found 'x8'");
+}
+
+#[test]
+fn error_position_of_missing_token() {
+    let input = "12 34\n56";
+
+    let error = ParseError::at(input, &input[5..5], "a third number");
+    assert_eq!((error.line, error.column, error.found.as_str()), (1, 6, "end of line"));
+
+    let error = ParseError::at_end(input, "a third number");
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 3, "end of input"));
+}
+
+#[test]
+fn parse_number_reports_token() {
+    let input = "seeds: 79 1x4";
+
+    assert_eq!(parse_number::<i64>(input, &input[7..9]), Ok(79));
+
+    let error = parse_number::<i64>(input, &input[10..]).unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (1, 11, "'1x4'"));
+}
+
+#[test]
+fn ensure_rectangular_points_at_deviation() {
+    assert_eq!(ensure_rectangular("ab\ncd\n"), Ok(2));
+
+    let error = ensure_rectangular("abc\nabcd\nab\n").unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 4, "'d'"));
+
+    let error = ensure_rectangular("abc\nab\n").unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 3, "end of line"));
+}
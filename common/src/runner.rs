@@ -0,0 +1,137 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use crate::input::InputSource;
+use crate::solution::{Error, Part, Puzzle};
+
+pub const INPUT_USAGE: &str = "\
+input selection:
+  (default)            the day's res/input
+  --example [NAME]     res/example, or res/example_NAME
+  --input PATH         any file
+  -                    read from stdin";
+
+/// Command line arguments shared by the day binaries and `aoc run`.
+#[derive(Debug)]
+pub struct RunArgs {
+    pub positional: Vec<String>,
+    pub part: Option<Part>,
+    pub source: InputSource,
+}
+
+impl RunArgs {
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut positional: Vec<String> = Vec::new();
+        let mut part: Option<Part> = None;
+        let mut sources: Vec<InputSource> = Vec::new();
+
+        let mut iter = args.iter().peekable();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--part" | "-p" => {
+                    let value = iter.next().ok_or("--part expects a value")?;
+                    part = Some(
+                        value
+                            .parse::<u8>()
+                            .ok()
+                            .and_then(Part::from_number)
+                            .ok_or(format!("invalid part '{value}', expected 1 or 2"))?
+                    );
+                },
+                "--example" | "-e" => {
+                    let name = iter.next_if(|next| !next.starts_with('-')).cloned();
+                    sources.push(InputSource::Example(name));
+                },
+                "--input" | "-i" => {
+                    let path = iter.next().ok_or("--input expects a path")?;
+                    sources.push(InputSource::File(PathBuf::from(path)));
+                },
+                "-" => sources.push(InputSource::Stdin),
+                option if option.starts_with('-') => return Err(format!("unknown option '{option}'")),
+                _ => positional.push(arg.clone()),
+            }
+        }
+
+        if sources.len() > 1 {
+            return Err("at most one of --example, --input and - can be given".to_string());
+        }
+
+        let source = sources.pop().unwrap_or(InputSource::Input);
+
+        Ok(RunArgs { positional, part, source })
+    }
+}
+
+/// Solves the requested part, or every solved part if none was requested, and prints the answers.
+pub fn run(puzzle: &dyn Puzzle, input_name: &str, input: &str, part: Option<Part>) -> Result<(), String> {
+    let parts: Vec<Part> = match part {
+        Some(part) => [part].into(),
+        None => Part::ALL.into(),
+    };
+
+    let is_explicit = part.is_some();
+
+    for part in parts {
+        match puzzle.solve(input, part) {
+            Ok(answer) => println!("result_part_{part}={answer}"),
+            Err(Error::Unsolved(_)) if !is_explicit => (),
+            Err(error) => {
+                let error = error.with_input_name(input_name);
+                return Err(format!("{} day {}: {error}", puzzle.year(), puzzle.day()));
+            },
+        }
+    }
+
+    Ok(())
+}
+
+/// Entry point of a day binary: `cargo run -p <day> -- [--part <1|2>] [input selection]`.
+pub fn main(puzzle: &dyn Puzzle, resources: &str) -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let outcome = RunArgs::parse(&args).and_then(|run_args| {
+        if let Some(unexpected) = run_args.positional.first() {
+            return Err(format!("unexpected argument '{unexpected}'"));
+        }
+
+        let input = run_args.source
+            .read(Path::new(resources))
+            .map_err(|error| error.to_string())?;
+
+        run(puzzle, &run_args.source.name(), &input, run_args.part)
+    });
+
+    match outcome {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}\nusage: [--part <1|2>] [input selection]\n{INPUT_USAGE}");
+            ExitCode::FAILURE
+        },
+    }
+}
+
+#[test]
+fn parse_run_args() {
+    let args: Vec<String> = ["2023", "8", "--example", "2", "--part", "1"].map(String::from).into();
+    let run_args = RunArgs::parse(&args).unwrap();
+
+    assert_eq!(run_args.positional, ["2023", "8"]);
+    assert_eq!(run_args.part, Some(Part::One));
+    assert_eq!(run_args.source, InputSource::Example(Some("2".to_string())));
+    assert_eq!(run_args.source.name(), "example_2");
+}
+
+#[test]
+fn parse_run_args_defaults_to_input() {
+    let args: Vec<String> = ["--example"].map(String::from).into();
+    assert_eq!(RunArgs::parse(&args).unwrap().source.name(), "example");
+
+    let run_args = RunArgs::parse(&[]).unwrap();
+    assert_eq!(run_args.part, None);
+    assert_eq!(run_args.source, InputSource::Input);
+}
+
+#[test]
+fn parse_run_args_rejects_multiple_sources() {
+    let args: Vec<String> = ["--example", "-"].map(String::from).into();
+    assert!(RunArgs::parse(&args).is_err());
+}
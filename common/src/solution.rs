@@ -0,0 +1,208 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+use crate::normalize::normalize;
+use crate::parse::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self: &Self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// The result of a single puzzle part.
+///
+/// Every integer type a day might compute fits into `Number`, so answers of
+/// different days can be compared and stored uniformly.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The part has not been implemented for this day.
+    Unsolved(Part),
+    /// The input does not match the expected format.
+    Parse(ParseError),
+    /// The input is well-formed, but has no answer for the part; says why.
+    Unsolvable(String),
+}
+
+impl Error {
+    /// Names the input in parse errors.
+    pub fn with_input_name(self, name: &str) -> Self {
+        match self {
+            Error::Parse(error) => Error::Parse(error.with_input_name(name)),
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Unsolved(part) => write!(f, "part {part} is not solved"),
+            Error::Parse(error) => write!(f, "{error}"),
+            Error::Unsolvable(reason) => write!(f, "no answer: {reason}"),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A puzzle of a single day: the input is parsed once and then shared by both parts.
+///
+/// Parts that have not been solved keep the default implementation, which
+/// reports [`Error::Unsolved`].
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input;
+
+    /// Whether runs of spaces and tabs are collapsed before parsing; see [`normalize`].
+    const COLLAPSE_WHITESPACE: bool = false;
+
+    /// Parses the input after [`normalize`]; error positions refer to the normalized text.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_1(_input: &Self::Input) -> Result<Answer, Error> {
+        Err(Error::Unsolved(Part::One))
+    }
+
+    fn part_2(_input: &Self::Input) -> Result<Answer, Error> {
+        Err(Error::Unsolved(Part::Two))
+    }
+}
+
+/// How long parsing and each solved part took in one run.
+#[derive(Debug, Clone)]
+pub struct PhaseTimes {
+    pub parse: Duration,
+    pub parts: Vec<(Part, Duration)>,
+}
+
+/// Object safe view of a [`Solution`], so days with different input types can
+/// live side by side in one registry.
+pub trait Puzzle: Sync {
+    fn year(self: &Self) -> u16;
+    fn day(self: &Self) -> u8;
+    fn solve(self: &Self, input: &str, part: Part) -> Result<Answer, Error>;
+
+    /// Parses once and solves every solved part, timing each phase separately.
+    fn time_phases(self: &Self, input: &str) -> Result<PhaseTimes, Error>;
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn year(self: &Self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(self: &Self) -> u8 {
+        S::DAY
+    }
+
+    fn solve(self: &Self, input: &str, part: Part) -> Result<Answer, Error> {
+        let parsed = S::parse(&normalize(input, S::COLLAPSE_WHITESPACE))?;
+
+        match part {
+            Part::One => S::part_1(&parsed),
+            Part::Two => S::part_2(&parsed),
+        }
+    }
+
+    fn time_phases(self: &Self, input: &str) -> Result<PhaseTimes, Error> {
+        let start = Instant::now();
+        let parsed = S::parse(&normalize(input, S::COLLAPSE_WHITESPACE))?;
+        let parse = start.elapsed();
+
+        let mut parts = Vec::new();
+
+        for part in Part::ALL {
+            let start = Instant::now();
+            let result = match part {
+                Part::One => S::part_1(&parsed),
+                Part::Two => S::part_2(&parsed),
+            };
+            let elapsed = start.elapsed();
+
+            match result {
+                Ok(answer) => {
+                    std::hint::black_box(answer);
+                    parts.push((part, elapsed));
+                },
+                Err(Error::Unsolved(_)) => (),
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(PhaseTimes { parse, parts })
+    }
+}
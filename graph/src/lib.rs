@@ -0,0 +1,171 @@
+mod search;
+mod structure;
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Index of a node in its [`Graph`], handed out in the order nodes are added.
+pub type NodeId = usize;
+
+/// A directed graph with weighted edges, as adjacency lists over interned
+/// node keys. Unweighted graphs use `()` as weight.
+///
+/// The edges of a node keep the order they were added in, and the same edge
+/// may be added more than once.
+#[derive(Debug, Clone)]
+pub struct Graph<K, W = ()> {
+    keys: Vec<K>,
+    ids: HashMap<K, NodeId>,
+    edges: Vec<Vec<(NodeId, W)>>,
+}
+
+impl<K: Clone + Eq + Hash, W> Graph<K, W> {
+    pub fn new() -> Self {
+        Graph { keys: Vec::new(), ids: HashMap::new(), edges: Vec::new() }
+    }
+
+    /// The id of `key`, which is added as a node if it is not one yet.
+    pub fn add_node(self: &mut Self, key: K) -> NodeId {
+        if let Some(id) = self.ids.get(&key) {
+            return *id;
+        }
+
+        let id = self.keys.len();
+        self.keys.push(key.clone());
+        self.ids.insert(key, id);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    /// Adds an edge, and its ends as nodes if they are not ones yet.
+    pub fn add_edge(self: &mut Self, from: K, to: K, weight: W) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.edges[from].push((to, weight));
+    }
+
+    pub fn id(self: &Self, key: &K) -> Option<NodeId> {
+        self.ids.get(key).copied()
+    }
+
+    pub fn contains(self: &Self, key: &K) -> bool {
+        self.ids.contains_key(key)
+    }
+
+    /// The graph of `keys`, in that order, and the edges between them.
+    /// Keys that are not nodes of this graph become nodes without edges.
+    pub fn induced_subgraph(self: &Self, keys: &[K]) -> Graph<K, W>
+    where
+        W: Clone,
+    {
+        let mut subgraph = Graph::new();
+        for key in keys {
+            subgraph.add_node(key.clone());
+        }
+
+        for from in subgraph.nodes() {
+            let Some(original) = self.id(subgraph.key(from)) else {
+                continue;
+            };
+
+            for (to, weight) in self.edges(original) {
+                if let Some(to) = subgraph.id(self.key(*to)) {
+                    subgraph.edges[from].push((to, weight.clone()));
+                }
+            }
+        }
+
+        subgraph
+    }
+}
+
+impl<K, W> Graph<K, W> {
+    pub fn key(self: &Self, id: NodeId) -> &K {
+        &self.keys[id]
+    }
+
+    /// Number of nodes.
+    pub fn len(self: &Self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(self: &Self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn nodes(self: &Self) -> std::ops::Range<NodeId> {
+        0..self.len()
+    }
+
+    /// Outgoing edges of `id` with their weights, in the order they were added.
+    pub fn edges(self: &Self, id: NodeId) -> &[(NodeId, W)] {
+        &self.edges[id]
+    }
+
+    pub fn successors(self: &Self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges[id].iter().map(|(to, _)| *to)
+    }
+
+    pub fn has_edge(self: &Self, from: NodeId, to: NodeId) -> bool {
+        self.successors(from).any(|successor| successor == to)
+    }
+}
+
+impl<K: Clone + Eq + Hash, W> Default for Graph<K, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone + Eq + Hash> FromIterator<(K, K)> for Graph<K> {
+    /// Unweighted graph of the given edges.
+    fn from_iter<I: IntoIterator<Item = (K, K)>>(edges: I) -> Self {
+        let mut graph = Graph::new();
+        for (from, to) in edges {
+            graph.add_edge(from, to, ());
+        }
+        graph
+    }
+}
+
+/// The graph of `a -> b` edges given as `"ab ..."`, for the tests.
+#[cfg(test)]
+fn graph_of(edges: &str) -> Graph<char> {
+    edges
+        .split_whitespace()
+        .map(|edge| {
+            let mut ends = edge.chars();
+            (ends.next().unwrap(), ends.next().unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn interned_nodes() {
+    let mut graph: Graph<&str, u32> = Graph::new();
+    graph.add_edge("a", "b", 3);
+    graph.add_edge("a", "c", 1);
+    graph.add_edge("b", "a", 2);
+
+    let (a, b, c) = (graph.id(&"a").unwrap(), graph.id(&"b").unwrap(), graph.id(&"c").unwrap());
+    assert_eq!((graph.len(), graph.add_node("c")), (3, c));
+    assert_eq!(graph.edges(a), &[(b, 3), (c, 1)]);
+    assert!(graph.has_edge(b, a) && !graph.has_edge(c, a));
+    assert_eq!(graph.key(b), &"b");
+    assert_eq!(graph.id(&"d"), None);
+}
+
+#[test]
+fn induced_subgraph() {
+    let graph = graph_of("ab bc cd da ac");
+    let subgraph = graph.induced_subgraph(&['c', 'a', 'x', 'a']);
+
+    let edges: Vec<(char, char)> = subgraph
+        .nodes()
+        .flat_map(|from| subgraph.successors(from).map(move |to| (from, to)))
+        .map(|(from, to)| (*subgraph.key(from), *subgraph.key(to)))
+        .collect();
+
+    assert_eq!(subgraph.len(), 3);
+    assert_eq!((subgraph.key(0), subgraph.key(2)), (&'c', &'x'));
+    assert_eq!(edges, [('a', 'c')]);
+}
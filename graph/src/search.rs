@@ -0,0 +1,150 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::ops::Add;
+use crate::{Graph, NodeId};
+
+impl<K, W> Graph<K, W> {
+    /// Number of edges on a shortest path from `start` to each node, `None`
+    /// for nodes that cannot be reached.
+    pub fn bfs(self: &Self, start: NodeId) -> Vec<Option<usize>> {
+        let mut distances: Vec<Option<usize>> = vec![None; self.len()];
+        let mut queue: VecDeque<NodeId> = VecDeque::from([start]);
+        distances[start] = Some(0);
+
+        while let Some(node) = queue.pop_front() {
+            let distance = distances[node].unwrap() + 1;
+
+            for next in self.successors(node) {
+                if distances[next].is_none() {
+                    distances[next] = Some(distance);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// The nodes reachable from `start` in depth-first preorder, taking the
+    /// edges of each node in the order they were added.
+    pub fn dfs(self: &Self, start: NodeId) -> Vec<NodeId> {
+        let mut visited = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+
+        while let Some(node) = stack.pop() {
+            if visited[node] {
+                continue;
+            }
+            visited[node] = true;
+            order.push(node);
+
+            stack.extend(self.successors(node).filter(|next| !visited[*next]).collect::<Vec<NodeId>>().into_iter().rev());
+        }
+
+        order
+    }
+}
+
+impl<K, W: Copy + Ord + Add<Output = W> + Default> Graph<K, W> {
+    /// Total weight of a lightest path from `start` to each node, `None` for
+    /// nodes that cannot be reached. Weights must not be negative.
+    pub fn dijkstra(self: &Self, start: NodeId) -> Vec<Option<W>> {
+        let mut costs: Vec<Option<W>> = vec![None; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((W::default(), start))]);
+        costs[start] = Some(W::default());
+
+        while let Some(Reverse((cost, node))) = queue.pop() {
+            if costs[node].is_some_and(|best| best < cost) {
+                continue;
+            }
+
+            for (next, weight) in self.edges(node) {
+                let next_cost = cost + *weight;
+                if costs[*next].is_none_or(|best| next_cost < best) {
+                    costs[*next] = Some(next_cost);
+                    queue.push(Reverse((next_cost, *next)));
+                }
+            }
+        }
+
+        costs
+    }
+
+    /// Weight and nodes of a lightest path from `start` to `goal`, guided by
+    /// `heuristic`, which must never overestimate the remaining weight.
+    pub fn a_star(self: &Self, start: NodeId, goal: NodeId, heuristic: impl Fn(NodeId) -> W) -> Option<(W, Vec<NodeId>)> {
+        let mut costs: Vec<Option<W>> = vec![None; self.len()];
+        let mut came_from: Vec<Option<NodeId>> = vec![None; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((heuristic(start), W::default(), start))]);
+        costs[start] = Some(W::default());
+
+        while let Some(Reverse((_, cost, node))) = queue.pop() {
+            if node == goal {
+                let mut path = vec![goal];
+                while let Some(previous) = came_from[*path.last().unwrap()] {
+                    path.push(previous);
+                }
+                path.reverse();
+                return Some((cost, path));
+            }
+
+            if costs[node].is_some_and(|best| best < cost) {
+                continue;
+            }
+
+            for (next, weight) in self.edges(node) {
+                let next_cost = cost + *weight;
+                if *next != start && costs[*next].is_none_or(|best| next_cost < best) {
+                    costs[*next] = Some(next_cost);
+                    came_from[*next] = Some(node);
+                    queue.push(Reverse((next_cost + heuristic(*next), next_cost, *next)));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+use crate::graph_of;
+
+#[test]
+fn breadth_and_depth_first() {
+    let graph = graph_of("ab ac bd cd de fa");
+    let id = |key: char| graph.id(&key).unwrap();
+
+    let distances = graph.bfs(id('a'));
+    assert_eq!(['a', 'b', 'c', 'd', 'e', 'f'].map(|key| distances[id(key)]), [Some(0), Some(1), Some(1), Some(2), Some(3), None]);
+
+    let order: String = graph.dfs(id('a')).into_iter().map(|node| graph.key(node)).collect();
+    assert_eq!(order, "abdec");
+}
+
+#[test]
+fn lightest_paths() {
+    let mut graph: Graph<char, u32> = Graph::new();
+    for (from, to, weight) in [('a', 'b', 7), ('a', 'c', 2), ('c', 'b', 3), ('b', 'd', 1), ('c', 'd', 8), ('d', 'e', 1), ('e', 'a', 1)] {
+        graph.add_edge(from, to, weight);
+    }
+    graph.add_node('f');
+    let id = |key: char| graph.id(&key).unwrap();
+
+    let costs = graph.dijkstra(id('a'));
+    assert_eq!(['a', 'b', 'c', 'd', 'e', 'f'].map(|key| costs[id(key)]), [Some(0), Some(5), Some(2), Some(6), Some(7), None]);
+
+    for goal in graph.nodes() {
+        let path = graph.a_star(id('a'), goal, |_| 0);
+        assert_eq!(path.as_ref().map(|(cost, _)| *cost), costs[goal]);
+
+        if let Some((cost, path)) = path {
+            let weights: u32 = path.windows(2).map(|w| graph.edges(w[0]).iter().find(|(to, _)| *to == w[1]).unwrap().1).sum();
+            assert_eq!((path[0], *path.last().unwrap(), weights), (id('a'), goal, cost));
+        }
+    }
+
+    let remaining = |node: NodeId| if *graph.key(node) == 'b' { 1 } else { 0 };
+    let (cost, path) = graph.a_star(id('a'), id('e'), remaining).unwrap();
+    assert_eq!((cost, path.into_iter().map(|node| *graph.key(node)).collect::<String>()), (7, "acbde".to_string()));
+}
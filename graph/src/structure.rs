@@ -0,0 +1,193 @@
+use std::collections::VecDeque;
+use crate::{Graph, NodeId};
+
+impl<K, W> Graph<K, W> {
+    /// All nodes such that every edge leads from an earlier to a later one,
+    /// or the nodes of a cycle if there is no such order.
+    pub fn topological_sort(self: &Self) -> Result<Vec<NodeId>, Vec<NodeId>> {
+        let mut n_incoming = vec![0; self.len()];
+        for node in self.nodes() {
+            for next in self.successors(node) {
+                n_incoming[next] += 1;
+            }
+        }
+
+        let mut ready: VecDeque<NodeId> = self.nodes().filter(|node| n_incoming[*node] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+
+        while let Some(node) = ready.pop_front() {
+            order.push(node);
+
+            for next in self.successors(node) {
+                n_incoming[next] -= 1;
+                if n_incoming[next] == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+
+        match order.len() == self.len() {
+            true => Ok(order),
+            false => Err(self.find_cycle().expect("nodes left unsorted lie on a cycle")),
+        }
+    }
+
+    /// The nodes of some cycle, each leading to the next and the last back to
+    /// the first; `None` if the graph is acyclic.
+    pub fn find_cycle(self: &Self) -> Option<Vec<NodeId>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State { Unvisited, OnPath, Done }
+
+        let mut state = vec![State::Unvisited; self.len()];
+
+        for root in self.nodes() {
+            if state[root] != State::Unvisited {
+                continue;
+            }
+
+            // The current path, each node with the number of its edges followed so far.
+            let mut path: Vec<(NodeId, usize)> = vec![(root, 0)];
+            state[root] = State::OnPath;
+
+            while let Some((node, i)) = path.last().copied() {
+                match self.edges(node).get(i) {
+                    Some((next, _)) => {
+                        path.last_mut().unwrap().1 += 1;
+
+                        match state[*next] {
+                            State::Unvisited => {
+                                state[*next] = State::OnPath;
+                                path.push((*next, 0));
+                            },
+                            State::OnPath => {
+                                let start = path.iter().position(|(on_path, _)| on_path == next).unwrap();
+                                return Some(path[start..].iter().map(|(on_path, _)| *on_path).collect());
+                            },
+                            State::Done => (),
+                        }
+                    },
+                    None => {
+                        state[node] = State::Done;
+                        path.pop();
+                    },
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Strongly connected components by Tarjan's algorithm. A component only
+    /// has edges to components listed before it.
+    pub fn strongly_connected_components(self: &Self) -> Vec<Vec<NodeId>> {
+        let mut index: Vec<Option<usize>> = vec![None; self.len()];
+        let mut low_link = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack: Vec<NodeId> = Vec::new();
+        let mut components: Vec<Vec<NodeId>> = Vec::new();
+        let mut next_index = 0;
+
+        for root in self.nodes() {
+            if index[root].is_some() {
+                continue;
+            }
+
+            // Emulates the recursion, each node with the number of its edges followed so far.
+            let mut calls: Vec<(NodeId, usize)> = vec![(root, 0)];
+
+            while let Some((node, i)) = calls.last().copied() {
+                if index[node].is_none() {
+                    index[node] = Some(next_index);
+                    low_link[node] = next_index;
+                    next_index += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                }
+
+                match self.edges(node).get(i) {
+                    Some((next, _)) => {
+                        calls.last_mut().unwrap().1 += 1;
+
+                        match index[*next] {
+                            None => calls.push((*next, 0)),
+                            Some(next_position) if on_stack[*next] => low_link[node] = low_link[node].min(next_position),
+                            Some(_) => (),
+                        }
+                    },
+                    None => {
+                        calls.pop();
+                        if let Some((caller, _)) = calls.last() {
+                            low_link[*caller] = low_link[*caller].min(low_link[node]);
+                        }
+
+                        if Some(low_link[node]) == index[node] {
+                            let mut component = Vec::new();
+                            loop {
+                                let member = stack.pop().unwrap();
+                                on_stack[member] = false;
+                                component.push(member);
+                                if member == node {
+                                    break;
+                                }
+                            }
+                            components.push(component);
+                        }
+                    },
+                }
+            }
+        }
+
+        components
+    }
+}
+
+#[cfg(test)]
+use crate::graph_of;
+
+#[test]
+fn topological_order() {
+    let graph = graph_of("ab ac bd cd de");
+    let order = graph.topological_sort().unwrap();
+    let position = |key: char| order.iter().position(|node| *graph.key(*node) == key).unwrap();
+
+    assert_eq!(order.len(), 5);
+    assert!(["ab", "ac", "bd", "cd", "de"].iter().all(|edge| {
+        let mut ends = edge.chars();
+        position(ends.next().unwrap()) < position(ends.next().unwrap())
+    }));
+    assert_eq!(graph.find_cycle(), None);
+}
+
+#[test]
+fn cycles_are_reported() {
+    let graph = graph_of("ab bc cd db de xx");
+
+    let cycle = graph.topological_sort().unwrap_err();
+    let keys: String = cycle.iter().map(|node| graph.key(*node)).collect();
+    assert_eq!(keys, "bcd");
+
+    for (i, node) in cycle.iter().enumerate() {
+        assert!(graph.has_edge(*node, cycle[(i + 1) % cycle.len()]));
+    }
+
+    let self_loop = graph_of("xx");
+    assert_eq!(self_loop.find_cycle(), Some(vec![0]));
+}
+
+#[test]
+fn strongly_connected() {
+    let graph = graph_of("ab bc ca cd de ed ef fg gf");
+
+    let components: Vec<String> = graph
+        .strongly_connected_components()
+        .into_iter()
+        .map(|component| {
+            let mut keys: Vec<char> = component.into_iter().map(|node| *graph.key(node)).collect();
+            keys.sort();
+            keys.into_iter().collect()
+        })
+        .collect();
+
+    assert_eq!(components, ["fg", "de", "abc"]);
+    assert_eq!(graph_of("ab bc").strongly_connected_components().len(), 3);
+}
@@ -0,0 +1,255 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use common::{ensure_rectangular, Direction, ParseError, Point, Vector};
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// Cells are addressed by [`Point`]s, `x` being the column and `y` the row.
+/// Indexing panics outside of the grid, [`Grid::get`] is the checked alternative.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    n_rows: usize,
+    n_columns: usize,
+}
+
+impl<T> Grid<T> {
+    /// Parses one cell per character; `cell` rejects characters it does not
+    /// know, which are then reported as not being `expected`.
+    pub fn parse(input: &str, cell: impl Fn(char) -> Option<T>, expected: &str) -> Result<Self, ParseError> {
+        let n_columns = ensure_rectangular(input)?;
+
+        let mut cells = Vec::with_capacity(input.len());
+        for line in input.lines() {
+            for (i, c) in line.char_indices() {
+                let parsed = cell(c).ok_or_else(|| ParseError::at(input, &line[i..i + c.len_utf8()], expected))?;
+                cells.push(parsed);
+            }
+        }
+
+        let n_rows = input.lines().count();
+
+        Ok(Grid { cells, n_rows, n_columns })
+    }
+
+    /// Grid of the given rows, which all need to have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let n_rows = rows.len();
+        let n_columns = rows.first().map(|row| row.len()).unwrap_or(0);
+        assert!(rows.iter().all(|row| row.len() == n_columns), "rows of different lengths");
+
+        Grid { cells: rows.into_iter().flatten().collect(), n_rows, n_columns }
+    }
+
+    pub fn n_rows(self: &Self) -> usize {
+        self.n_rows
+    }
+
+    pub fn n_columns(self: &Self) -> usize {
+        self.n_columns
+    }
+
+    /// Index of `point` into the cells, if it is in the grid.
+    fn cell_index(self: &Self, point: Point) -> Option<usize> {
+        match point.to_unsigned() {
+            Some((column, row)) if column < self.n_columns && row < self.n_rows => Some(row * self.n_columns + column),
+            _ => None,
+        }
+    }
+
+    pub fn contains(self: &Self, point: Point) -> bool {
+        self.cell_index(point).is_some()
+    }
+
+    pub fn get(self: &Self, point: Point) -> Option<&T> {
+        self.cell_index(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(self: &mut Self, point: Point) -> Option<&mut T> {
+        self.cell_index(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Orthogonal neighbours of `point` within the grid, clockwise from north.
+    pub fn neighbours_4(self: &Self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| point + direction.to_vector())
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// Orthogonal and diagonal neighbours of `point` within the grid, clockwise from north.
+    pub fn neighbours_8(self: &Self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Vector::AROUND
+            .into_iter()
+            .map(move |step| point + step)
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// All points of the grid, row by row.
+    pub fn points(self: &Self) -> impl Iterator<Item = Point> {
+        let n_columns = self.n_columns;
+        (0..self.n_rows).flat_map(move |row| (0..n_columns).map(move |column| point_at(column, row)))
+    }
+
+    /// All cells with their points, row by row.
+    pub fn iter(self: &Self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn row(self: &Self, row: usize) -> &[T] {
+        &self.cells[row * self.n_columns..(row + 1) * self.n_columns]
+    }
+
+    pub fn rows(self: &Self) -> impl Iterator<Item = &[T]> {
+        (0..self.n_rows).map(|row| self.row(row))
+    }
+
+    pub fn column(self: &Self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.n_columns, "column {column} is outside of the grid");
+        self.cells.iter().skip(column).step_by(self.n_columns)
+    }
+
+    pub fn columns(self: &Self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.n_columns).map(|column| self.column(column))
+    }
+
+    pub fn map<U>(self: &Self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), n_rows: self.n_rows, n_columns: self.n_columns }
+    }
+
+    /// One line per row, with a character per cell.
+    pub fn render(self: &Self, f: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&f).collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(n_rows: usize, n_columns: usize, value: T) -> Self {
+        Grid { cells: vec![value; n_rows * n_columns], n_rows, n_columns }
+    }
+
+    /// Grid of `n_rows` × `n_columns` whose cell at `(column, row)` is the cell of
+    /// this grid at `source(column, row)`.
+    fn rearranged(self: &Self, n_rows: usize, n_columns: usize, source: impl Fn(usize, usize) -> (usize, usize)) -> Self {
+        let cells = (0..n_rows)
+            .flat_map(|row| (0..n_columns).map(move |column| (column, row)))
+            .map(|(column, row)| {
+                let (source_column, source_row) = source(column, row);
+                self.cells[source_row * self.n_columns + source_column].clone()
+            })
+            .collect();
+
+        Grid { cells, n_rows, n_columns }
+    }
+
+    /// Mirrors the grid at its main diagonal.
+    pub fn transpose(self: &Self) -> Self {
+        self.rearranged(self.n_columns, self.n_rows, |column, row| (row, column))
+    }
+
+    pub fn rotate_clockwise(self: &Self) -> Self {
+        let n_rows = self.n_rows;
+        self.rearranged(self.n_columns, self.n_rows, |column, row| (row, n_rows - 1 - column))
+    }
+
+    pub fn rotate_counter_clockwise(self: &Self) -> Self {
+        let n_columns = self.n_columns;
+        self.rearranged(self.n_columns, self.n_rows, |column, row| (n_columns - 1 - row, column))
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontally(self: &Self) -> Self {
+        let n_columns = self.n_columns;
+        self.rearranged(self.n_rows, self.n_columns, |column, row| (n_columns - 1 - column, row))
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertically(self: &Self) -> Self {
+        let n_rows = self.n_rows;
+        self.rearranged(self.n_rows, self.n_columns, |column, row| (column, n_rows - 1 - row))
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid that accepts any character.
+    pub fn parse_chars(input: &str) -> Result<Self, ParseError> {
+        Self::parse(input, Some, "any character")
+    }
+}
+
+/// The point at `column` and `row`, which always fits as the cells are in memory.
+fn point_at(column: usize, row: usize) -> Point {
+    Point::from_unsigned(column, row).expect("grid coordinates fit into i64")
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(self: &Self, point: Point) -> &T {
+        match self.cell_index(point) {
+            Some(i) => &self.cells[i],
+            None => panic!("{point} is outside of the grid"),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(self: &mut Self, point: Point) -> &mut T {
+        match self.cell_index(point) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{point} is outside of the grid"),
+        }
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|c| *c))
+    }
+}
+
+#[test]
+fn parse_and_access() {
+    let grid = Grid::parse("12\n34\n56\n", |c| c.to_digit(10), "a digit").unwrap();
+
+    assert_eq!((grid.n_rows(), grid.n_columns()), (3, 2));
+    assert_eq!(grid[Point::new(1, 2)], 6);
+    assert_eq!(grid.get(Point::new(0, 1)), Some(&3));
+    assert_eq!(grid.get(Point::new(0, 3)), None);
+    assert_eq!(grid.get(Point::new(-1, 0)), None);
+
+    assert_eq!(grid.row(1), &[3, 4]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<u32>>(), vec![2, 4, 6]);
+    assert_eq!(grid.columns().count(), 2);
+    assert_eq!(grid.iter().last(), Some((Point::new(1, 2), &6)));
+
+    let error = Grid::parse("12\n3x\n", |c| c.to_digit(10), "a digit").err().unwrap();
+    assert_eq!(error.to_string(), "2:2: expected a digit, found 'x'");
+    assert!(Grid::parse_chars("12\n3\n").is_err());
+}
+
+#[test]
+fn neighbours() {
+    let grid = Grid::new(3, 3, ());
+    let points = |points: &[(i64, i64)]| points.iter().map(|(x, y)| Point::new(*x, *y)).collect::<Vec<Point>>();
+
+    assert_eq!(grid.neighbours_4(Point::ORIGIN).collect::<Vec<Point>>(), points(&[(1, 0), (0, 1)]));
+    assert_eq!(grid.neighbours_4(Point::new(1, 1)).count(), 4);
+    assert_eq!(grid.neighbours_8(Point::new(1, 0)).collect::<Vec<Point>>(), points(&[(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]));
+    assert_eq!(grid.neighbours_8(Point::new(1, 1)).count(), 8);
+}
+
+#[test]
+fn rearrange() {
+    let grid = Grid::parse_chars("abc\ndef\n").unwrap();
+
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad\n");
+    assert_eq!(grid.flip_horizontally().to_string(), "cba\nfed\n");
+    assert_eq!(grid.flip_vertically().to_string(), "def\nabc\n");
+    assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+    assert_eq!(grid.map(|c| c.to_ascii_uppercase()).render(|c| *c), "ABC\nDEF\n");
+}
@@ -0,0 +1,35 @@
+mod map;
+mod set;
+
+pub use map::OffsetMap;
+pub use set::{Interval, IntervalSet};
+
+/// Deterministic pseudo-random inputs for the property tests.
+#[cfg(test)]
+mod random {
+    use crate::Interval;
+
+    /// Range that the property tests draw their values from.
+    pub const DOMAIN: Interval = Interval { start: -20, end: 20 };
+
+    pub struct Random(u64);
+
+    impl Random {
+        pub fn new(seed: u64) -> Self {
+            Random(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+        }
+
+        /// xorshift64
+        fn next(self: &mut Self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        /// A value from `0..n`.
+        pub fn below(self: &mut Self, n: i64) -> i64 {
+            (self.next() % n as u64) as i64
+        }
+    }
+}
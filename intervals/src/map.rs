@@ -0,0 +1,331 @@
+use std::fmt;
+use crate::set::{Interval, IntervalSet};
+
+/// Maps each integer to itself plus the offset of the piece it falls into;
+/// integers outside of all pieces keep their value.
+///
+/// Pieces are kept as sorted breakpoints `(start, offset)`, each valid up to
+/// the next start. The first start is `i64::MIN` and neighbouring offsets
+/// differ, so equal maps have equal representations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OffsetMap {
+    breakpoints: Vec<(i64, i64)>,
+}
+
+/// End of the last piece: one past `i64::MAX`, hence the wider type.
+const END: i128 = i64::MAX as i128 + 1;
+
+fn clamp(value: i128) -> i64 {
+    value.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// Sorted, non-overlapping breakpoints into the canonical form of [`OffsetMap`].
+fn canonical(breakpoints: impl IntoIterator<Item = (i64, i64)>) -> Vec<(i64, i64)> {
+    let mut result: Vec<(i64, i64)> = vec![(i64::MIN, 0)];
+
+    for (start, offset) in breakpoints {
+        if result.last().is_some_and(|(last_start, _)| *last_start == start) {
+            result.pop();
+        }
+        if result.last().is_some_and(|(_, last_offset)| *last_offset == offset) {
+            continue;
+        }
+        result.push((start, offset));
+    }
+
+    if result.is_empty() || result[0].0 != i64::MIN {
+        result.insert(0, (i64::MIN, 0));
+    }
+
+    result
+}
+
+impl OffsetMap {
+    pub fn identity() -> Self {
+        OffsetMap { breakpoints: vec![(i64::MIN, 0)] }
+    }
+
+    /// Map that adds `offset` to the integers of each interval. Fails with the
+    /// index of a piece that overlaps a piece starting before it.
+    pub fn from_pieces(pieces: impl IntoIterator<Item = (Interval, i64)>) -> Result<Self, usize> {
+        let mut pieces: Vec<(usize, Interval, i64)> = pieces
+            .into_iter()
+            .enumerate()
+            .filter(|(_, (interval, _))| !interval.is_empty())
+            .map(|(i, (interval, offset))| (i, interval, offset))
+            .collect();
+        pieces.sort_by_key(|(_, interval, _)| interval.start);
+
+        let mut breakpoints = Vec::new();
+        let mut covered_until = i64::MIN;
+
+        for (i, interval, offset) in pieces {
+            if interval.start < covered_until {
+                return Err(i);
+            }
+
+            breakpoints.push((interval.start, offset));
+            breakpoints.push((interval.end, 0));
+            covered_until = interval.end;
+        }
+
+        Ok(OffsetMap { breakpoints: canonical(breakpoints) })
+    }
+
+    /// All pieces as `(start, end, offset)`, in the wider type to cover every `i64`.
+    fn wide_pieces(self: &Self) -> impl Iterator<Item = (i128, i128, i64)> + '_ {
+        self.breakpoints.iter().enumerate().map(|(i, (start, offset))| {
+            let end = self.breakpoints.get(i + 1).map(|(next, _)| *next as i128).unwrap_or(END);
+            (*start as i128, end, *offset)
+        })
+    }
+
+    /// All pieces that move their integers, as interval and offset.
+    pub fn pieces(self: &Self) -> Vec<(Interval, i64)> {
+        self.wide_pieces()
+            .filter(|(_, _, offset)| *offset != 0)
+            .map(|(start, end, offset)| (Interval::new(start as i64, clamp(end)), offset))
+            .collect()
+    }
+
+    /// The integers where the offset changes, in ascending order.
+    pub fn breakpoints(self: &Self) -> impl Iterator<Item = i64> + '_ {
+        self.breakpoints.iter().skip(1).map(|(start, _)| *start)
+    }
+
+    pub fn offset_at(self: &Self, value: i64) -> i64 {
+        let i = self.breakpoints.partition_point(|(start, _)| *start <= value);
+        self.breakpoints[i - 1].1
+    }
+
+    /// `None` if the result does not fit in an `i64`.
+    pub fn apply(self: &Self, value: i64) -> Option<i64> {
+        value.checked_add(self.offset_at(value))
+    }
+
+    pub fn apply_interval(self: &Self, interval: Interval) -> IntervalSet {
+        let first = self.breakpoints.partition_point(|(start, _)| *start <= interval.start) - 1;
+
+        self.wide_pieces()
+            .skip(first)
+            .take_while(|(start, _, _)| *start < interval.end as i128)
+            .filter_map(|(start, end, offset)| {
+                Interval::new(start as i64, clamp(end))
+                    .intersection(&interval)
+                    .map(|part| part.shift(offset))
+            })
+            .collect()
+    }
+
+    /// The image of `set`.
+    pub fn apply_set(self: &Self, set: &IntervalSet) -> IntervalSet {
+        set.intervals()
+            .iter()
+            .flat_map(|interval| self.apply_interval(*interval).intervals().to_vec())
+            .collect()
+    }
+
+    /// The map that applies this map first and `next` to its result.
+    pub fn then(self: &Self, next: &OffsetMap) -> OffsetMap {
+        let mut breakpoints = Vec::new();
+
+        for (start, end, offset) in self.wide_pieces() {
+            let (image_start, image_end) = (start + offset as i128, end + offset as i128);
+
+            for (next_start, next_end, next_offset) in next.wide_pieces() {
+                let (common_start, common_end) = (image_start.max(next_start), image_end.min(next_end));
+                if common_start < common_end {
+                    breakpoints.push((clamp(common_start - offset as i128), offset + next_offset));
+                }
+            }
+        }
+
+        OffsetMap { breakpoints: canonical(breakpoints) }
+    }
+
+    /// All integers that the map sends into `set`.
+    pub fn preimage(self: &Self, set: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+
+        for (start, end, offset) in self.wide_pieces() {
+            let (image_start, image_end) = (start + offset as i128, end + offset as i128);
+
+            for interval in set.intervals() {
+                let (common_start, common_end) = (image_start.max(interval.start as i128), image_end.min(interval.end as i128));
+                if common_start < common_end {
+                    intervals.push(Interval::new(clamp(common_start - offset as i128), clamp(common_end - offset as i128)));
+                }
+            }
+        }
+
+        intervals.into_iter().collect()
+    }
+
+    /// The inverse map, if every integer has exactly one preimage.
+    pub fn inverse(self: &Self) -> Option<OffsetMap> {
+        let pieces: Vec<(i128, i128, i64)> = self.wide_pieces().collect();
+        let last = pieces.len() - 1;
+
+        // The outer pieces reach to infinity; their images have to stay outermost.
+        let mut images: Vec<(i128, i128, i64)> = pieces
+            .iter()
+            .enumerate()
+            .map(|(i, (start, end, offset))| {
+                let image_start = if i == 0 { i128::MIN } else { start + *offset as i128 };
+                let image_end = if i == last { i128::MAX } else { end + *offset as i128 };
+                (image_start, image_end, *offset)
+            })
+            .collect();
+        images.sort();
+
+        if images.windows(2).any(|w| w[0].1 != w[1].0) || images[0].0 != i128::MIN || images[last].1 != i128::MAX {
+            return None;
+        }
+
+        let breakpoints = images.iter().map(|(image_start, _, offset)| (clamp(*image_start), -offset));
+        Some(OffsetMap { breakpoints: canonical(breakpoints) })
+    }
+}
+
+impl fmt::Display for OffsetMap {
+    /// One line per piece that moves its integers, e.g. `[98, 100) -> [50, 52)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (interval, offset) in self.pieces() {
+            writeln!(f, "{interval} -> {}", interval.shift(offset))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use crate::random::{Random, DOMAIN};
+
+#[cfg(test)]
+impl Random {
+    fn offset_map(self: &mut Self) -> OffsetMap {
+        let mut pieces = Vec::new();
+        let mut start = DOMAIN.start + self.below(6);
+
+        while start < DOMAIN.end {
+            let end = (start + 1 + self.below(6)).min(DOMAIN.end);
+            pieces.push((Interval::new(start, end), self.below(13) - 6));
+            start = end + self.below(3);
+        }
+
+        OffsetMap::from_pieces(pieces).unwrap()
+    }
+
+    /// A map that cuts [`DOMAIN`] into blocks and shuffles them, which is invertible.
+    fn block_shuffle(self: &mut Self) -> OffsetMap {
+        let mut blocks = Vec::new();
+        let mut start = DOMAIN.start;
+
+        while start < DOMAIN.end {
+            let end = (start + 1 + self.below(8)).min(DOMAIN.end);
+            blocks.push(Interval::new(start, end));
+            start = end;
+        }
+
+        let mut shuffled = blocks.clone();
+        for i in (1..shuffled.len()).rev() {
+            shuffled.swap(i, self.below(i as i64 + 1) as usize);
+        }
+
+        let mut target = DOMAIN.start;
+        let pieces = shuffled.into_iter().map(|block| {
+            let offset = target - block.start;
+            target += block.len() as i64;
+            (block, offset)
+        });
+
+        OffsetMap::from_pieces(pieces.collect::<Vec<_>>()).unwrap()
+    }
+}
+
+/// Wide enough to contain every preimage of a value in [`DOMAIN`].
+#[cfg(test)]
+const WINDOW: std::ops::Range<i64> = DOMAIN.start - 20..DOMAIN.end + 20;
+
+#[test]
+fn maps_match_brute_force() {
+    let mut random = Random::new(5);
+
+    for _ in 0..300 {
+        let (map, next) = (random.offset_map(), random.offset_map());
+        let set = random.interval_set();
+
+        let image = map.apply_set(&set);
+        for value in WINDOW {
+            let expected = WINDOW.clone().any(|x| set.contains(x) && map.apply(x) == Some(value));
+            assert_eq!(image.contains(value), expected, "image of {set} under\n{map}at {value}");
+        }
+
+        let composed = map.then(&next);
+        for value in WINDOW {
+            assert_eq!(composed.apply(value), map.apply(value).and_then(|image| next.apply(image)), "composition at {value}");
+        }
+
+        let preimage = map.preimage(&set);
+        for value in WINDOW {
+            assert_eq!(preimage.contains(value), set.contains(map.apply(value).unwrap()), "preimage of {set} at {value}");
+        }
+        assert!(preimage.min().is_none_or(|min| WINDOW.contains(&min)) && preimage.max().is_none_or(|max| WINDOW.contains(&max)));
+    }
+}
+
+#[test]
+fn inverse_matches_brute_force() {
+    let mut random = Random::new(18);
+    let mut n_invertible = 0;
+
+    for _ in 0..300 {
+        let map = match random.below(2) {
+            0 => random.offset_map(),
+            _ => random.block_shuffle(),
+        };
+        let images: Vec<i64> = WINDOW.map(|value| map.apply(value).unwrap()).collect();
+
+        let is_injective = images.iter().collect::<std::collections::HashSet<&i64>>().len() == images.len();
+        let is_surjective = (DOMAIN.start..DOMAIN.end).all(|value| images.contains(&value));
+
+        match map.inverse() {
+            Some(inverse) => {
+                n_invertible += 1;
+                assert!(is_injective && is_surjective);
+                for value in WINDOW {
+                    assert_eq!(map.apply(value).and_then(|image| inverse.apply(image)), Some(value));
+                }
+                assert_eq!(inverse.inverse(), Some(map));
+            },
+            None => assert!(!is_injective || !is_surjective, "\n{map}is invertible"),
+        }
+    }
+
+    let swap = OffsetMap::from_pieces([(Interval::new(0, 2), 1), (Interval::new(2, 3), -2)]).unwrap();
+    assert_eq!(swap.inverse().map(|inverse| inverse.apply_set(&Interval::new(0, 3).into())), Some(Interval::new(0, 3).into()));
+    assert!(n_invertible > 0);
+}
+
+#[test]
+fn overlapping_pieces_are_rejected() {
+    let map = OffsetMap::from_pieces([(Interval::new(5, 8), 1), (Interval::new(0, 6), 2)]);
+    assert_eq!(map, Err(0));
+
+    let map = OffsetMap::from_pieces([(Interval::new(98, 100), -48), (Interval::new(50, 98), 2)]).unwrap();
+    assert_eq!(map.to_string(), "[50, 98) -> [52, 100)\n[98, 100) -> [50, 52)\n");
+    assert_eq!(map.breakpoints().collect::<Vec<i64>>(), vec![50, 98, 100]);
+    assert_eq!(map.then(&map.inverse().unwrap()), OffsetMap::identity());
+}
+
+#[test]
+fn values_at_the_bounds() {
+    let map = OffsetMap::from_pieces([(Interval::new(i64::MIN, i64::MIN + 2), -1), (Interval::new(i64::MAX - 2, i64::MAX), 2)]).unwrap();
+
+    assert_eq!((map.apply(i64::MIN), map.apply(i64::MIN + 1)), (None, Some(i64::MIN)));
+    assert_eq!((map.apply(i64::MAX - 2), map.apply(i64::MAX - 1), map.apply(i64::MAX)), (Some(i64::MAX), None, Some(i64::MAX)));
+    assert_eq!(OffsetMap::identity().apply(i64::MAX), Some(i64::MAX));
+
+    assert_eq!(Interval::with_length(i64::MAX, 1), None);
+    assert_eq!(Interval::with_length(i64::MAX - 1, 1), Some(Interval::new(i64::MAX - 1, i64::MAX)));
+    assert_eq!((Interval::with_length(i64::MIN, -1), Interval::with_length(0, -1)), (None, None));
+}
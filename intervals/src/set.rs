@@ -0,0 +1,238 @@
+use std::fmt;
+
+/// The integers from `start` up to, but excluding, `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        assert!(start <= end, "interval [{start}, {end}) ends before it starts");
+        Interval { start, end }
+    }
+
+    /// `None` if the length is negative or the end does not fit in an `i64`.
+    pub fn with_length(start: i64, length: i64) -> Option<Self> {
+        match length < 0 {
+            true => None,
+            false => Some(Self::new(start, start.checked_add(length)?)),
+        }
+    }
+
+    pub fn len(self: &Self) -> u64 {
+        self.end.abs_diff(self.start)
+    }
+
+    pub fn is_empty(self: &Self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn contains(self: &Self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The common part of both intervals, if there is one.
+    pub fn intersection(self: &Self, other: &Interval) -> Option<Interval> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+
+        match start < end {
+            true => Some(Interval { start, end }),
+            false => None,
+        }
+    }
+
+    /// The interval moved by `offset`.
+    pub fn shift(self: &Self, offset: i64) -> Interval {
+        Interval { start: self.start + offset, end: self.end + offset }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// A set of integers, kept as sorted intervals that neither overlap nor touch,
+/// so equal sets have equal representations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet { intervals: Vec::new() }
+    }
+
+    /// The sorted intervals of the set; adjacent intervals are merged.
+    pub fn intervals(self: &Self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(self: &Self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(self: &Self) -> u64 {
+        self.intervals.iter().map(|interval| interval.len()).sum()
+    }
+
+    pub fn min(self: &Self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(self: &Self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end - 1)
+    }
+
+    pub fn contains(self: &Self, value: i64) -> bool {
+        let i = self.intervals.partition_point(|interval| interval.end <= value);
+        self.intervals.get(i).is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn insert(self: &mut Self, interval: Interval) {
+        *self = self.union(&IntervalSet::from(interval));
+    }
+
+    pub fn union(self: &Self, other: &IntervalSet) -> IntervalSet {
+        self.intervals.iter().chain(other.intervals.iter()).copied().collect()
+    }
+
+    pub fn intersection(self: &Self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+
+            if let Some(common) = a.intersection(&b) {
+                intervals.push(common);
+            }
+
+            if a.end <= b.end {
+                i += 1;
+            }
+            else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    /// The integers of this set that are not in `other`.
+    pub fn difference(self: &Self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let mut j = 0;
+
+        for interval in &self.intervals {
+            let mut start = interval.start;
+
+            while j < other.intervals.len() && other.intervals[j].end <= start {
+                j += 1;
+            }
+
+            let mut k = j;
+            while k < other.intervals.len() && other.intervals[k].start < interval.end {
+                let cut = other.intervals[k];
+                if start < cut.start {
+                    intervals.push(Interval::new(start, cut.start));
+                }
+                start = start.max(cut.end);
+                k += 1;
+            }
+
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    /// The set moved by `offset`.
+    pub fn shift(self: &Self, offset: i64) -> IntervalSet {
+        IntervalSet { intervals: self.intervals.iter().map(|interval| interval.shift(offset)).collect() }
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        [interval].into_iter().collect()
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    /// Sorts the intervals and merges those that overlap or touch.
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut sorted: Vec<Interval> = iter.into_iter().filter(|interval| !interval.is_empty()).collect();
+        sorted.sort();
+
+        let mut intervals: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval),
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let intervals: Vec<String> = self.intervals.iter().map(|interval| interval.to_string()).collect();
+        write!(f, "{{{}}}", intervals.join(", "))
+    }
+}
+
+#[cfg(test)]
+use crate::random::{Random, DOMAIN};
+
+#[cfg(test)]
+impl Random {
+    pub fn interval_set(self: &mut Self) -> IntervalSet {
+        (0..self.below(5))
+            .map(|_| {
+                let start = DOMAIN.start + self.below(DOMAIN.len() as i64);
+                Interval::new(start, (start + self.below(8)).min(DOMAIN.end))
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn set_operations_match_brute_force() {
+    let mut random = Random::new(16);
+
+    for _ in 0..500 {
+        let (a, b) = (random.interval_set(), random.interval_set());
+        let (union, intersection, difference) = (a.union(&b), a.intersection(&b), a.difference(&b));
+
+        for value in DOMAIN.start - 1..=DOMAIN.end {
+            assert_eq!(union.contains(value), a.contains(value) || b.contains(value), "{a} ∪ {b} at {value}");
+            assert_eq!(intersection.contains(value), a.contains(value) && b.contains(value), "{a} ∩ {b} at {value}");
+            assert_eq!(difference.contains(value), a.contains(value) && !b.contains(value), "{a} \\ {b} at {value}");
+        }
+
+        for set in [&union, &intersection, &difference] {
+            assert!(set.intervals().windows(2).all(|w| w[0].end < w[1].start), "{set} is not canonical");
+            assert_eq!(set.len() as usize, (DOMAIN.start..DOMAIN.end).filter(|value| set.contains(*value)).count());
+        }
+    }
+}
+
+#[test]
+fn adjacent_intervals_merge() {
+    let set: IntervalSet = [Interval::new(5, 7), Interval::new(0, 3), Interval::new(3, 5), Interval::new(9, 9)].into_iter().collect();
+
+    assert_eq!(set.intervals(), &[Interval::new(0, 7)]);
+    assert_eq!((set.min(), set.max(), set.len()), (Some(0), Some(6), 7));
+    assert_eq!(set.to_string(), "{[0, 7)}");
+}